@@ -24,7 +24,11 @@ along with this program. If not, see https://www.gnu.org/licenses/
 
 */
 
-use clap::Parser;
+use clap::{Parser, Subcommand, ValueEnum};
+use crate::modules::uptime::UptimeFormat;
+use crate::output::colors::ColorMode;
+use crate::output::image::ImageProtocol;
+use crate::output::LogoPosition;
 
 #[derive(Parser, Debug)]
 #[command(name = "hyperfetch")]
@@ -33,21 +37,63 @@ use clap::Parser;
 #[command(about = "HyperFetch", long_about = None)]
 pub struct Args {
     
+    #[command(subcommand)]
+    pub command: Option<Command>,
+    
     #[arg(short, long)]
     pub logo: Option<String>,
     
-    #[arg(short, long)]
+    #[arg(short, long, value_name = "WHEN|THEME", global = true)]
     pub color: Option<String>,
     
+    #[arg(long, global = true)]
+    pub query_palette: bool,
+    
+    #[arg(long, value_name = "N=COLOR,...")]
+    pub logo_color: Option<String>,
+    
+    #[arg(long, value_name = "PATH")]
+    pub image: Option<String>,
+    
+    #[arg(long, value_name = "PROTOCOL")]
+    pub image_protocol: Option<ImageProtocol>,
+    
+    #[arg(long, value_name = "COLUMNS")]
+    pub image_width: Option<usize>,
+    
+    #[arg(long, value_name = "POSITION")]
+    pub logo_position: Option<LogoPosition>,
+    
+    #[arg(long, value_name = "N")]
+    pub logo_padding_left: Option<usize>,
+    
+    #[arg(long, value_name = "N")]
+    pub logo_padding_right: Option<usize>,
+    
+    #[arg(long, value_name = "N")]
+    pub logo_padding_top: Option<usize>,
+    
     #[arg(short, long)]
     pub small: bool,
     
+    #[arg(long, value_name = "COLUMNS")]
+    pub small_below: Option<usize>,
+    
+    #[arg(long, value_name = "COLUMNS")]
+    pub hide_logo_below: Option<usize>,
+    
+    #[arg(long)]
+    pub wrap: bool,
+    
     #[arg(short, long)]
     pub all: bool,
     
     #[arg(long)]
     pub no_logo: bool,
     
+    #[arg(long, value_name = "SECONDS", num_args = 0..=1, default_missing_value = "2")]
+    pub watch: Option<f64>,
+    
     #[arg(long)]
     pub benchmark: bool,
     
@@ -60,6 +106,147 @@ pub struct Args {
     #[arg(long)]
     pub json: bool,
     
+    #[arg(long, value_name = "FORMAT", global = true)]
+    pub format: Option<String>,
+    
+    #[arg(long, value_name = "PATH")]
+    pub save: Option<String>,
+    
+    #[arg(long, value_name = "PATH")]
+    pub diff_with: Option<String>,
+    
+    #[arg(long, global = true)]
+    pub diff_all: bool,
+    
+    #[arg(long)]
+    pub oneline: bool,
+    
+    #[arg(long, value_name = "MODULES")]
+    pub oneline_modules: Option<String>,
+    
+    #[arg(long, value_name = "SEPARATOR")]
+    pub separator: Option<String>,
+    
+    #[arg(long, value_name = "MODULE[.FIELD]")]
+    pub get: Option<String>,
+    
     #[arg(long)]
+    pub key_value: bool,
+    
+    #[arg(long, global = true)]
     pub no_icons: bool,
+    
+    #[arg(long, global = true)]
+    pub pipe: bool,
+    
+    #[arg(long, value_name = "TEMPLATE")]
+    pub format_user: Option<String>,
+    
+    #[arg(long, value_name = "TEMPLATE")]
+    pub format_os: Option<String>,
+    
+    #[arg(long, value_name = "TEMPLATE")]
+    pub format_host: Option<String>,
+    
+    #[arg(long, value_name = "TEMPLATE")]
+    pub format_kernel: Option<String>,
+    
+    #[arg(long, value_name = "TEMPLATE")]
+    pub format_virtualization: Option<String>,
+    
+    #[arg(long, value_name = "TEMPLATE")]
+    pub format_cpu: Option<String>,
+    
+    #[arg(long, value_name = "TEMPLATE")]
+    pub format_gpu: Option<String>,
+    
+    #[arg(long, value_name = "TEMPLATE")]
+    pub format_memory: Option<String>,
+    
+    #[arg(long, value_name = "TEMPLATE")]
+    pub format_disk: Option<String>,
+    
+    #[arg(long, value_name = "TEMPLATE")]
+    pub format_uptime: Option<String>,
+    
+    #[arg(long, value_name = "TEMPLATE")]
+    pub format_load: Option<String>,
+    
+    #[arg(long, value_name = "TEMPLATE")]
+    pub format_shell: Option<String>,
+    
+    #[arg(long, value_name = "TEMPLATE")]
+    pub format_terminal: Option<String>,
+    
+    #[arg(long, value_name = "TEMPLATE")]
+    pub format_desktop: Option<String>,
+    
+    #[arg(long, value_name = "TEMPLATE")]
+    pub format_display: Option<String>,
+    
+    #[arg(long, value_name = "TEMPLATE")]
+    pub format_battery: Option<String>,
+    
+    #[arg(long, value_name = "TEMPLATE")]
+    pub format_packages: Option<String>,
+    
+    #[arg(long, value_name = "TEMPLATE")]
+    pub format_network: Option<String>,
+    
+    #[arg(long, value_name = "FORMAT", default_value = "long")]
+    pub uptime_format: UptimeFormat,
+    
+    #[arg(long)]
+    pub list_themes: bool,
+    
+    #[arg(long)]
+    pub list_logos: bool,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    Diff {
+        old: String,
+        new: String,
+    },
+}
+
+impl Args {
+    pub fn color_mode(&self) -> ColorMode {
+        self.color.as_deref()
+            .and_then(|value| ColorMode::from_str(value, true).ok())
+            .unwrap_or_default()
+    }
+    
+    pub fn theme_name(&self) -> &str {
+        match self.color.as_deref() {
+            Some(value) if ColorMode::from_str(value, true).is_ok_and(|mode| mode != ColorMode::Auto) => "default",
+            Some(value) => value,
+            None => "default",
+        }
+    }
+    
+    pub fn format_override(&self, module: &str) -> Option<&str> {
+        match module {
+            "user" => self.format_user.as_deref(),
+            "os" => self.format_os.as_deref(),
+            "host" => self.format_host.as_deref(),
+            "kernel" => self.format_kernel.as_deref(),
+            "virtualization" => self.format_virtualization.as_deref(),
+            "cpu" => self.format_cpu.as_deref(),
+            "gpu" => self.format_gpu.as_deref(),
+            "memory" => self.format_memory.as_deref(),
+            "disk" => self.format_disk.as_deref(),
+            "uptime" => self.format_uptime.as_deref(),
+            "load" => self.format_load.as_deref(),
+            "shell" => self.format_shell.as_deref(),
+            "terminal" => self.format_terminal.as_deref(),
+            "desktop" => self.format_desktop.as_deref(),
+            "display" => self.format_display.as_deref(),
+            "battery" => self.format_battery.as_deref(),
+            "packages" => self.format_packages.as_deref(),
+            "network" => self.format_network.as_deref(),
+            _ => None,
+        }
+    }
 }
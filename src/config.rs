@@ -0,0 +1,173 @@
+/*
+* Copyright (C) 2026 compiledkernel-idk <https://github.com/compiledkernel-idk>
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program. If not, see <https://www.gnu.org/licenses/>
+*/
+
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub type Table = HashMap<String, HashMap<String, String>>;
+
+pub fn config_home() -> PathBuf {
+    env::var("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| Path::new(&env::var("HOME").unwrap_or_default()).join(".config"))
+}
+
+pub fn config_dir() -> PathBuf {
+    config_home().join("hyperfetch")
+}
+
+pub fn config_path() -> PathBuf {
+    config_dir().join("config.toml")
+}
+
+pub fn load() -> Table {
+    read_toml(&config_path()).unwrap_or_default()
+}
+
+pub fn read_toml(path: &Path) -> Option<Table> {
+    fs::read_to_string(path).ok().map(|content| parse_toml(&content))
+}
+
+pub fn parse_toml(content: &str) -> Table {
+    parse(content, true)
+}
+
+pub fn parse_ini(content: &str) -> Table {
+    parse(content, false)
+}
+
+fn parse(content: &str, inline_comments: bool) -> Table {
+    let mut table: Table = HashMap::new();
+    let mut section = String::new();
+    
+    for line in content.lines() {
+        let line = if inline_comments { strip_comment(line) } else { line }.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+            continue;
+        }
+        
+        if line.starts_with('[') && line.ends_with(']') {
+            section = line.trim_matches(|c| c == '[' || c == ']')
+                .split('.')
+                .map(|part| unquote(part.trim()))
+                .collect::<Vec<_>>()
+                .join(".");
+            continue;
+        }
+        
+        if let Some((key, value)) = line.split_once('=') {
+            table.entry(section.clone())
+                .or_default()
+                .insert(unquote(key.trim()), unquote(value.trim()));
+        }
+    }
+    
+    table
+}
+
+fn strip_comment(line: &str) -> &str {
+    let mut quote = None;
+    let mut escaped = false;
+    
+    for (i, c) in line.char_indices() {
+        match quote {
+            Some('"') if escaped => escaped = false,
+            Some('"') if c == '\\' => escaped = true,
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if c == '"' || c == '\'' => quote = Some(c),
+            None if c == '#' => return &line[..i],
+            None => {}
+        }
+    }
+    
+    line
+}
+
+fn unquote(value: &str) -> String {
+    if value.len() >= 2 && value.starts_with('\'') && value.ends_with('\'') {
+        return value[1..value.len() - 1].to_string();
+    }
+    
+    if value.len() < 2 || !value.starts_with('"') || !value.ends_with('"') {
+        return value.to_string();
+    }
+    
+    let mut result = String::new();
+    let mut chars = value[1..value.len() - 1].chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => result.push('\n'),
+            Some('t') => result.push('\t'),
+            Some('e') => result.push('\x1b'),
+            Some('u') => {
+                let code: String = chars.by_ref().take(4).collect();
+                if let Some(ch) = u32::from_str_radix(&code, 16).ok().and_then(char::from_u32) {
+                    result.push(ch);
+                }
+            }
+            Some(other) => result.push(other),
+            None => {}
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn parses_toml_sections_and_values() {
+        let table = parse_toml("top = 1\n[format]\nkernel = \"{release}\" # comment\n[font.normal]\nfamily = 'Fira Code'\n");
+        assert_eq!(table[""]["top"], "1");
+        assert_eq!(table["format"]["kernel"], "{release}");
+        assert_eq!(table["font.normal"]["family"], "Fira Code");
+    }
+    
+    #[test]
+    fn keeps_hash_inside_quotes() {
+        let table = parse_toml("[colors]\nlabel = \"#ff8800\" # orange\n");
+        assert_eq!(table["colors"]["label"], "#ff8800");
+    }
+    
+    #[test]
+    fn unescapes_basic_strings() {
+        let table = parse_toml("value = \"a\\tb\\\"c\\u00e9\"\n");
+        assert_eq!(table[""]["value"], "a\tb\"cé");
+    }
+    
+    #[test]
+    fn ini_keeps_inline_hash() {
+        let table = parse_ini("; comment\n# comment\n[colors]\nforeground=#dcdccc\n[Settings]\ngtk-theme-name=\"Adwaita\"\n");
+        assert_eq!(table["colors"]["foreground"], "#dcdccc");
+        assert_eq!(table["Settings"]["gtk-theme-name"], "Adwaita");
+    }
+    
+    #[test]
+    fn ignores_lines_without_assignment() {
+        let table = parse_ini("[main]\njust text\nfont=monospace:size=8\n");
+        assert_eq!(table["main"].len(), 1);
+        assert_eq!(table["main"]["font"], "monospace:size=8");
+    }
+}
@@ -25,6 +25,10 @@ along with this program. If not, see https://www.gnu.org/licenses/
 */
 
 use colored::*;
+use crate::logos;
+use crate::modules::os;
+use crate::output::colors::{resolve_theme, theme_names, BUILTIN_THEMES};
+use crate::output::progress::create_bar;
 
 pub fn show_palette() {
     println!("{}", "Terminal Color Palette".bright_cyan().bold());
@@ -49,3 +53,43 @@ pub fn show_palette() {
     print!("{}", "███".bright_cyan());
     println!("{}", "███".bright_white());
 }
+
+pub fn show_themes(query_palette: bool) {
+    println!("{}", "Available Themes".bright_cyan().bold());
+    println!("{}", "─".repeat(40).bright_black());
+    
+    let logo_colors = logos::logo_colors(&logos::resolve_logo_name(&os::get_info().logo_candidates()));
+    
+    for name in theme_names() {
+        let Ok(theme) = resolve_theme(&name, &logo_colors, query_palette) else {
+            continue;
+        };
+        let swatches: String = [
+            theme.label_color,
+            theme.value_color,
+            theme.title_color,
+            theme.separator_color,
+            theme.bar_good_color,
+            theme.bar_warn_color,
+            theme.bar_bad_color,
+        ]
+        .iter()
+        .map(|color| theme.paint("██", *color))
+        .collect();
+        
+        let source = if BUILTIN_THEMES.contains(&name.as_str()) { "" } else { " (user)" };
+        
+        println!();
+        println!("{}{}  {}", theme.apply_title(&name), source.bright_black(), swatches);
+        println!("  {} {} {}",
+            theme.apply_label("Label"),
+            theme.apply_separator("│"),
+            theme.apply_value("value")
+        );
+        println!("  {} {} {}",
+            create_bar(30.0, 10, &theme),
+            create_bar(65.0, 10, &theme),
+            create_bar(90.0, 10, &theme)
+        );
+    }
+}
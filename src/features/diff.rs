@@ -0,0 +1,491 @@
+/*
+* Copyright (C) 2026 compiledkernel-idk <https://github.com/compiledkernel-idk>
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program. If not, see <https://www.gnu.org/licenses/>
+*/
+
+use std::collections::HashMap;
+use std::fs;
+use std::iter::Peekable;
+use std::str::Chars;
+use crate::modules::SystemInfo;
+use crate::output::colors::Theme;
+use crate::output::structured::{self, json_string, OutputFormat};
+
+#[derive(Debug, Clone, PartialEq)]
+enum Json {
+    Null,
+    Bool(bool),
+    Number(String),
+    Text(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Change {
+    Changed(String, String),
+    Added(String),
+    Removed(String),
+}
+
+const LIST_KEYS: &[&str] = &["mount", "name", "model", "resource"];
+
+const VOLATILE: &[(&str, &[&str])] = &[
+    ("uptime", &["*"]),
+    ("load", &["*"]),
+    ("cpu", &["usage", "freq_mhz", "freq_ghz", "temp"]),
+    ("memory", &["used", "free", "available", "percent", "used_bytes", "free_bytes", "available_bytes", "swap_used", "swap_percent", "swap_used_bytes"]),
+    ("disk", &["used", "available", "percent", "used_bytes", "available_bytes", "all_used_bytes", "all_percent"]),
+    ("network", &["rx_rate", "tx_rate", "rx_bytes", "tx_bytes"]),
+    ("battery", &["percent", "state", "power_watts", "time_remaining", "ac_online"]),
+];
+
+pub fn save_snapshot(info: &SystemInfo, path: &str) -> Result<(), String> {
+    let snapshot = structured::to_string(info, OutputFormat::Json);
+    fs::write(path, format!("{}\n", snapshot.trim_end()))
+        .map_err(|e| format!("Cannot write snapshot '{}': {}", path, e))
+}
+
+pub fn load_snapshot(path: &str) -> Result<Vec<(String, String)>, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("Cannot read snapshot '{}': {}", path, e))?;
+    let json = parse_json(&content)
+        .ok_or_else(|| format!("'{}' is not a valid hyperfetch snapshot", path))?;
+    Ok(flatten_snapshot(&json))
+}
+
+pub fn current_snapshot(info: &SystemInfo) -> Vec<(String, String)> {
+    let json = parse_json(&structured::to_string(info, OutputFormat::Json)).unwrap_or(Json::Null);
+    flatten_snapshot(&json)
+}
+
+pub fn run(old_name: &str, old: &[(String, String)], new_name: &str, new: &[(String, String)], theme: &Theme, include_volatile: bool, json: bool) -> bool {
+    let changes: Vec<(String, Change)> = compare(old, new)
+        .into_iter()
+        .filter(|(path, _)| include_volatile || !is_volatile(path))
+        .collect();
+    
+    if json {
+        print_json(&changes);
+    } else {
+        print_human(old_name, new_name, &changes, theme);
+    }
+    
+    !changes.is_empty()
+}
+
+fn compare(old: &[(String, String)], new: &[(String, String)]) -> Vec<(String, Change)> {
+    let old_values: HashMap<&str, &str> = old.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect();
+    let new_values: HashMap<&str, &str> = new.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect();
+    let mut changes = Vec::new();
+    
+    for (path, value) in old {
+        match new_values.get(path.as_str()) {
+            Some(new_value) if *new_value != value => {
+                changes.push((path.clone(), Change::Changed(value.clone(), new_value.to_string())));
+            }
+            Some(_) => {}
+            None => changes.push((path.clone(), Change::Removed(value.clone()))),
+        }
+    }
+    for (path, value) in new {
+        if !old_values.contains_key(path.as_str()) {
+            changes.push((path.clone(), Change::Added(value.clone())));
+        }
+    }
+    
+    changes.sort_by_key(|(path, _)| module_order(path));
+    changes
+}
+
+fn module_order(path: &str) -> usize {
+    let module = path.split('.').next().unwrap_or("");
+    crate::modules::MODULES.iter().position(|m| *m == module).unwrap_or(usize::MAX)
+}
+
+fn is_volatile(path: &str) -> bool {
+    let module = path.split('.').next().unwrap_or("");
+    let field = path.rsplit('.').next().unwrap_or("");
+    VOLATILE.iter().any(|(name, fields)| *name == module && (fields.contains(&"*") || fields.contains(&field)))
+}
+
+fn print_human(old_name: &str, new_name: &str, changes: &[(String, Change)], theme: &Theme) {
+    println!("{} {} {}",
+        theme.apply_title(old_name),
+        theme.apply_separator("→"),
+        theme.apply_title(new_name)
+    );
+    
+    if changes.is_empty() {
+        println!();
+        println!("{}", theme.apply_value("No differences"));
+        return;
+    }
+    
+    let width = changes.iter()
+        .map(|(path, _)| path.split_once('.').map(|(_, field)| field.len()).unwrap_or(0))
+        .max()
+        .unwrap_or(0);
+    
+    let mut current_module = "";
+    for (path, change) in changes {
+        let (module, field) = path.split_once('.').unwrap_or((path.as_str(), ""));
+        if module != current_module {
+            println!();
+            println!("{}", theme.apply_label(module));
+            current_module = module;
+        }
+        
+        let field = format!("{:<width$}", field, width = width);
+        match change {
+            Change::Changed(old, new) => println!("  {}  {} {} {}",
+                theme.apply_value(&field),
+                theme.paint(old, theme.bar_bad_color),
+                theme.apply_separator("→"),
+                theme.paint(new, theme.bar_good_color)
+            ),
+            Change::Added(new) => println!("{} {}  {}",
+                theme.paint("+", theme.bar_good_color),
+                theme.apply_value(&field),
+                theme.paint(new, theme.bar_good_color)
+            ),
+            Change::Removed(old) => println!("{} {}  {}",
+                theme.paint("-", theme.bar_bad_color),
+                theme.apply_value(&field),
+                theme.paint(old, theme.bar_bad_color)
+            ),
+        }
+    }
+    
+    println!();
+    println!("{}", theme.apply_value(&format!(
+        "{} difference{}",
+        changes.len(),
+        if changes.len() == 1 { "" } else { "s" }
+    )));
+}
+
+fn print_json(changes: &[(String, Change)]) {
+    let entries: Vec<String> = changes.iter()
+        .map(|(path, change)| {
+            let (kind, old, new) = match change {
+                Change::Changed(old, new) => ("changed", json_string(old), json_string(new)),
+                Change::Added(new) => ("added", "null".to_string(), json_string(new)),
+                Change::Removed(old) => ("removed", json_string(old), "null".to_string()),
+            };
+            format!(
+                "  {{\"path\": {}, \"change\": \"{}\", \"old\": {}, \"new\": {}}}",
+                json_string(path), kind, old, new
+            )
+        })
+        .collect();
+    
+    if entries.is_empty() {
+        println!("[]");
+    } else {
+        println!("[\n{}\n]", entries.join(",\n"));
+    }
+}
+
+fn flatten_snapshot(json: &Json) -> Vec<(String, String)> {
+    let mut values = Vec::new();
+    if let Json::Object(modules) = json {
+        for (module, value) in modules {
+            flatten(module, value, &mut values);
+        }
+    }
+    values
+}
+
+fn flatten(prefix: &str, value: &Json, values: &mut Vec<(String, String)>) {
+    match value {
+        Json::Null => {}
+        Json::Bool(value) => values.push((prefix.to_string(), value.to_string())),
+        Json::Number(number) => values.push((prefix.to_string(), number.clone())),
+        Json::Text(text) => values.push((prefix.to_string(), text.clone())),
+        Json::Object(fields) => {
+            for (name, value) in fields {
+                flatten(&format!("{}.{}", prefix, name), value, values);
+            }
+        }
+        Json::Array(items) if items.iter().any(|item| matches!(item, Json::Object(_) | Json::Array(_))) => {
+            let mut seen: HashMap<String, usize> = HashMap::new();
+            for (index, item) in items.iter().enumerate() {
+                let key = match list_key(item) {
+                    Some(key) => {
+                        let count = seen.entry(key.clone()).or_insert(0);
+                        *count += 1;
+                        if *count == 1 { key } else { format!("{}#{}", key, count) }
+                    }
+                    None => index.to_string(),
+                };
+                flatten(&format!("{}[{}]", prefix, key), item, values);
+            }
+        }
+        Json::Array(items) => {
+            let mut scalars = Vec::new();
+            for item in items {
+                flatten("", item, &mut scalars);
+            }
+            let joined: Vec<String> = scalars.into_iter().map(|(_, value)| value).collect();
+            if !joined.is_empty() {
+                values.push((prefix.to_string(), joined.join(", ")));
+            }
+        }
+    }
+}
+
+fn list_key(item: &Json) -> Option<String> {
+    let Json::Object(fields) = item else {
+        return None;
+    };
+    LIST_KEYS.iter().find_map(|key| {
+        fields.iter().find_map(|(name, value)| match value {
+            Json::Text(text) if name == key && !text.is_empty() => Some(text.clone()),
+            _ => None,
+        })
+    })
+}
+
+fn parse_json(content: &str) -> Option<Json> {
+    let mut chars = content.chars().peekable();
+    let value = parse_value(&mut chars)?;
+    skip_whitespace(&mut chars);
+    chars.peek().is_none().then_some(value)
+}
+
+fn skip_whitespace(chars: &mut Peekable<Chars>) {
+    while chars.peek().is_some_and(|c| c.is_whitespace()) {
+        chars.next();
+    }
+}
+
+fn parse_value(chars: &mut Peekable<Chars>) -> Option<Json> {
+    skip_whitespace(chars);
+    match *chars.peek()? {
+        '{' => {
+            chars.next();
+            let mut fields = Vec::new();
+            loop {
+                skip_whitespace(chars);
+                match chars.next()? {
+                    '}' if fields.is_empty() => break,
+                    '"' => {}
+                    _ => return None,
+                }
+                let key = parse_string(chars)?;
+                skip_whitespace(chars);
+                if chars.next()? != ':' {
+                    return None;
+                }
+                fields.push((key, parse_value(chars)?));
+                skip_whitespace(chars);
+                match chars.next()? {
+                    ',' => continue,
+                    '}' => break,
+                    _ => return None,
+                }
+            }
+            Some(Json::Object(fields))
+        }
+        '[' => {
+            chars.next();
+            let mut items = Vec::new();
+            skip_whitespace(chars);
+            if chars.peek() == Some(&']') {
+                chars.next();
+                return Some(Json::Array(items));
+            }
+            loop {
+                items.push(parse_value(chars)?);
+                skip_whitespace(chars);
+                match chars.next()? {
+                    ',' => continue,
+                    ']' => break,
+                    _ => return None,
+                }
+            }
+            Some(Json::Array(items))
+        }
+        '"' => {
+            chars.next();
+            parse_string(chars).map(Json::Text)
+        }
+        c if c == '-' || c.is_ascii_digit() => {
+            let mut number = String::new();
+            while let Some(&c) = chars.peek() {
+                if !(c.is_ascii_digit() || "+-.eE".contains(c)) {
+                    break;
+                }
+                number.push(c);
+                chars.next();
+            }
+            number.parse::<f64>().ok().map(|_| Json::Number(number))
+        }
+        _ => {
+            let word: String = std::iter::from_fn(|| chars.next_if(|c| c.is_ascii_alphabetic())).collect();
+            match word.as_str() {
+                "true" => Some(Json::Bool(true)),
+                "false" => Some(Json::Bool(false)),
+                "null" => Some(Json::Null),
+                _ => None,
+            }
+        }
+    }
+}
+
+fn parse_string(chars: &mut Peekable<Chars>) -> Option<String> {
+    let mut result = String::new();
+    loop {
+        match chars.next()? {
+            '"' => return Some(result),
+            '\\' => match chars.next()? {
+                'n' => result.push('\n'),
+                't' => result.push('\t'),
+                'r' => result.push('\r'),
+                'b' => result.push('\u{8}'),
+                'f' => result.push('\u{c}'),
+                'u' => {
+                    let mut code = parse_hex4(chars)?;
+                    if (0xd800..0xdc00).contains(&code) {
+                        let mut lookahead = chars.clone();
+                        if lookahead.next() == Some('\\') && lookahead.next() == Some('u') {
+                            if let Some(low) = parse_hex4(&mut lookahead).filter(|low| (0xdc00..0xe000).contains(low)) {
+                                code = 0x10000 + ((code - 0xd800) << 10) + (low - 0xdc00);
+                                *chars = lookahead;
+                            }
+                        }
+                    }
+                    result.push(char::from_u32(code).unwrap_or('\u{fffd}'));
+                }
+                other => result.push(other),
+            },
+            c => result.push(c),
+        }
+    }
+}
+
+fn parse_hex4(chars: &mut Peekable<Chars>) -> Option<u32> {
+    let code: String = chars.by_ref().take(4).collect();
+    if code.len() != 4 || !code.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    u32::from_str_radix(&code, 16).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::{Fields, Value};
+    
+    fn snapshot(modules: Fields) -> Vec<(String, String)> {
+        let path = std::env::temp_dir().join(format!("hyperfetch-diff-{}-{:?}.json", std::process::id(), std::thread::current().id()));
+        let path = path.to_string_lossy().to_string();
+        fs::write(&path, structured::to_json(&Value::Map(modules), 0)).unwrap();
+        let values = load_snapshot(&path);
+        let _ = fs::remove_file(&path);
+        values.unwrap()
+    }
+    
+    fn disk(mount: &'static str, used: u64) -> Value {
+        Value::Map(vec![("mount", mount.into()), ("used_bytes", used.into()), ("fs", "ext4".into())])
+    }
+    
+    #[test]
+    fn round_trips_escaped_text() {
+        let text = "quote \" slash \\ tab \t newline \n bell \u{7} emoji 🦀 é";
+        let values = snapshot(vec![("kernel", Value::Map(vec![("release", text.into()), ("missing", Value::Null)]))]);
+        assert_eq!(values, vec![("kernel.release".to_string(), text.to_string())]);
+    }
+    
+    #[test]
+    fn round_trips_numbers_lists_and_bools() {
+        let values = snapshot(vec![("os", Value::Map(vec![
+            ("id_like", vec!["ubuntu", "debian"].into()),
+            ("immutable", false.into()),
+            ("generation", 42u32.into()),
+            ("percent", 12.5f64.into()),
+        ]))]);
+        assert_eq!(values, vec![
+            ("os.id_like".to_string(), "ubuntu, debian".to_string()),
+            ("os.immutable".to_string(), "false".to_string()),
+            ("os.generation".to_string(), "42".to_string()),
+            ("os.percent".to_string(), "12.5".to_string()),
+        ]);
+    }
+    
+    #[test]
+    fn decodes_surrogate_pairs() {
+        let json = parse_json("{\"a\": \"\\ud83e\\udd80\"}").unwrap();
+        assert_eq!(json, Json::Object(vec![("a".to_string(), Json::Text("🦀".to_string()))]));
+    }
+    
+    #[test]
+    fn keeps_characters_after_lone_surrogate() {
+        let json = parse_json("{\"a\": \"\\ud83exy\", \"b\": \"\\ud83e\\n\"}").unwrap();
+        assert_eq!(json, Json::Object(vec![
+            ("a".to_string(), Json::Text("\u{fffd}xy".to_string())),
+            ("b".to_string(), Json::Text("\u{fffd}\n".to_string())),
+        ]));
+    }
+    
+    #[test]
+    fn rejects_malformed_json() {
+        assert_eq!(parse_json("{\"a\": }"), None);
+        assert_eq!(parse_json("{\"a\": \"\\u12\"}"), None);
+        assert_eq!(parse_json("[1, 2] trailing"), None);
+    }
+    
+    #[test]
+    fn compares_saved_snapshots() {
+        let old = snapshot(vec![
+            ("kernel", Value::Map(vec![("release", "6.1.0".into())])),
+            ("os", Value::Map(vec![("codename", "bookworm".into())])),
+        ]);
+        let new = snapshot(vec![
+            ("kernel", Value::Map(vec![("release", "6.6.0".into())])),
+            ("os", Value::Map(vec![("variant", "Workstation".into())])),
+        ]);
+        assert_eq!(compare(&old, &new), vec![
+            ("os.codename".to_string(), Change::Removed("bookworm".to_string())),
+            ("os.variant".to_string(), Change::Added("Workstation".to_string())),
+            ("kernel.release".to_string(), Change::Changed("6.1.0".to_string(), "6.6.0".to_string())),
+        ]);
+    }
+    
+    #[test]
+    fn matches_list_items_by_key() {
+        let old = snapshot(vec![("disk", Value::Map(vec![
+            ("disks", Value::List(vec![disk("/", 10), disk("/home", 20)])),
+        ]))]);
+        let new = snapshot(vec![("disk", Value::Map(vec![
+            ("disks", Value::List(vec![disk("/boot", 5), disk("/home", 20), disk("/", 10)])),
+        ]))]);
+        assert_eq!(compare(&old, &new), vec![
+            ("disk.disks[/boot].mount".to_string(), Change::Added("/boot".to_string())),
+            ("disk.disks[/boot].used_bytes".to_string(), Change::Added("5".to_string())),
+            ("disk.disks[/boot].fs".to_string(), Change::Added("ext4".to_string())),
+        ]);
+    }
+    
+    #[test]
+    fn skips_volatile_fields() {
+        assert!(is_volatile("uptime.total_seconds"));
+        assert!(is_volatile("disk.disks[/].used_bytes"));
+        assert!(is_volatile("network.interfaces[eth0].rx_bytes"));
+        assert!(!is_volatile("disk.disks[/].total_bytes"));
+        assert!(!is_volatile("gpu.gpus[GeForce RTX 3080].driver"));
+    }
+}
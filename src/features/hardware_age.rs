@@ -24,7 +24,428 @@ along with this program. If not, see https://www.gnu.org/licenses/
 
 */
 
-pub fn estimate_hardware_age(_cpu_model: &str) -> Option<String> {
+use chrono::Datelike;
+
+pub const INTEL_CORE_GENERATIONS: &[(u32, u32)] = &[
+    (1, 2009),
+    (2, 2011),
+    (3, 2012),
+    (4, 2013),
+    (5, 2015),
+    (6, 2015),
+    (7, 2017),
+    (8, 2017),
+    (9, 2018),
+    (10, 2019),
+    (11, 2021),
+    (12, 2021),
+    (13, 2022),
+    (14, 2023),
+];
+
+pub const INTEL_CORE_MOBILE_GENERATIONS: &[(u32, u32)] = &[
+    (10, 2019),
+    (11, 2020),
+    (12, 2022),
+    (13, 2023),
+];
+
+pub const INTEL_CORE_ULTRA_SERIES: &[(u32, u32)] = &[
+    (1, 2023),
+    (2, 2024),
+    (3, 2025),
+];
+
+pub const INTEL_XEON_SCALABLE: &[(u32, u32)] = &[
+    (1, 2017),
+    (2, 2019),
+    (3, 2021),
+    (4, 2023),
+    (5, 2023),
+    (6, 2024),
+];
+
+pub const INTEL_XEON_REVISIONS: &[(u32, u32)] = &[
+    (1, 2012),
+    (2, 2013),
+    (3, 2014),
+    (4, 2016),
+    (5, 2016),
+    (6, 2017),
+];
+
+pub const AMD_RYZEN_SERIES: &[(u32, u32)] = &[
+    (1, 2017),
+    (2, 2018),
+    (3, 2019),
+    (4, 2020),
+    (5, 2020),
+    (6, 2022),
+    (7, 2022),
+    (8, 2024),
+    (9, 2024),
+];
+
+pub const AMD_EPYC_MODELS: &[(u32, u32, u32)] = &[
+    (3, 1, 2018),
+    (4, 4, 2024),
+    (4, 5, 2025),
+    (7, 1, 2017),
+    (7, 2, 2019),
+    (7, 3, 2021),
+    (8, 4, 2023),
+    (9, 4, 2022),
+    (9, 5, 2024),
+];
+
+pub const AMD_EPYC_GENERATIONS: &[(u32, u32)] = &[
+    (1, 2017),
+    (2, 2019),
+    (3, 2021),
+    (4, 2022),
+    (5, 2024),
+];
+
+pub const NAMED_CPU_RELEASES: &[(&str, u32)] = &[
+    ("ryzen ai", 2024),
+    ("threadripper pro 9", 2025),
+    ("threadripper pro 7", 2023),
+    ("threadripper pro 5", 2022),
+    ("threadripper pro 3", 2020),
+    ("threadripper 9", 2025),
+    ("threadripper 7", 2023),
+    ("threadripper 3", 2019),
+    ("threadripper 2", 2018),
+    ("threadripper 1", 2017),
+    ("fx-", 2011),
+    ("phenom ii", 2009),
+    ("phenom", 2007),
+    ("athlon ii", 2009),
+    ("athlon 64", 2003),
+    ("core2", 2006),
+    ("core 2", 2006),
+    ("pentium 4", 2000),
+    ("atom", 2008),
+    ("apple m4", 2024),
+    ("apple m3", 2023),
+    ("apple m2", 2022),
+    ("apple m1", 2020),
+    ("cortex-x925", 2024),
+    ("cortex-a725", 2024),
+    ("cortex-x4", 2023),
+    ("cortex-a720", 2023),
+    ("cortex-x3", 2022),
+    ("cortex-a715", 2022),
+    ("cortex-x2", 2021),
+    ("cortex-a710", 2021),
+    ("cortex-a510", 2021),
+    ("cortex-x1", 2020),
+    ("cortex-a78", 2020),
+    ("cortex-a77", 2019),
+    ("cortex-a76", 2018),
+    ("cortex-a75", 2017),
+    ("cortex-a55", 2017),
+    ("cortex-a73", 2016),
+    ("cortex-a72", 2015),
+    ("cortex-a57", 2012),
+    ("cortex-a53", 2012),
+    ("cortex-a35", 2015),
+    ("cortex-a15", 2010),
+    ("cortex-a9", 2007),
+    ("cortex-a8", 2005),
+    ("cortex-a7", 2011),
+    ("neoverse-v2", 2022),
+    ("neoverse-n2", 2021),
+    ("neoverse-v1", 2020),
+    ("neoverse-n1", 2019),
+    ("snapdragon x elite", 2024),
+    ("snapdragon x plus", 2024),
+    ("ampere altra", 2020),
+    ("ampereone", 2023),
+];
+
+pub const NVIDIA_NAMED: &[(&str, u32)] = &[
+    ("rtx pro", 2025),
+    ("ada generation", 2023),
+    ("quadro rtx", 2018),
+    ("titan rtx", 2018),
+    ("rtx a", 2021),
+];
+
+pub const NVIDIA_RTX_SERIES: &[(u32, u32)] = &[
+    (20, 2018),
+    (30, 2020),
+    (40, 2022),
+    (50, 2025),
+];
+
+pub const NVIDIA_GTX_SERIES: &[(u32, u32)] = &[
+    (4, 2010),
+    (5, 2010),
+    (6, 2012),
+    (7, 2013),
+    (9, 2014),
+    (10, 2016),
+    (16, 2019),
+];
+
+pub const AMD_RADEON_RDNA_SERIES: &[(u32, u32)] = &[
+    (5, 2019),
+    (6, 2020),
+    (7, 2022),
+    (9, 2025),
+];
+
+pub const AMD_RADEON_POLARIS_SERIES: &[(u32, u32)] = &[
+    (4, 2016),
+    (5, 2017),
+];
+
+pub const AMD_RADEON_NAMED: &[(&str, u32)] = &[
+    ("radeon vii", 2019),
+    ("rx vega", 2017),
+    ("r9 fury", 2015),
+    ("r9 3", 2015),
+    ("r9 2", 2013),
+    ("r7 2", 2013),
+];
+
+pub const INTEL_GPU_SERIES: &[(&str, u32)] = &[
+    ("arc b", 2024),
+    ("arc a", 2022),
+    ("iris xe", 2020),
+    ("uhd graphics 7", 2021),
+    ("uhd graphics 6", 2017),
+    ("hd graphics 6", 2015),
+    ("hd graphics 5", 2015),
+    ("hd graphics 4", 2013),
+];
+
+fn lookup(table: &[(u32, u32)], key: u32) -> Option<u32> {
+    table.iter().find(|(k, _)| *k == key).map(|(_, year)| *year)
+}
+
+fn lookup_prefix(table: &[(&str, u32)], model: &str) -> Option<u32> {
+    table.iter()
+        .find(|(needle, _)| {
+            model.match_indices(needle).any(|(start, _)| {
+                !model[..start].ends_with(|c: char| c.is_ascii_alphanumeric())
+            })
+        })
+        .map(|(_, year)| *year)
+}
+
+fn model_number_after<'a>(model: &'a str, marker: &str) -> Option<&'a str> {
+    let start = model.find(marker)? + marker.len();
+    let rest = &model[start..];
+    let end = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+    let digits = &rest[..end];
+    if digits.is_empty() {
+        None
+    } else {
+        Some(digits)
+    }
+}
+
+pub fn cpu_release_year(model: &str) -> Option<u32> {
+    let model = model.to_lowercase().replace("(tm)", "").replace("(r)", "");
+    let model = model.split_whitespace().collect::<Vec<_>>().join(" ");
+    
+    if let Some(year) = lookup_prefix(NAMED_CPU_RELEASES, &model) {
+        return Some(year);
+    }
+    
+    if model.contains("core") && model.contains("ultra") {
+        let series = model.split_whitespace()
+            .find(|part| part.len() >= 3 && part.starts_with(|c: char| c.is_ascii_digit()))
+            .and_then(|part| part.chars().next()?.to_digit(10))?;
+        return lookup(INTEL_CORE_ULTRA_SERIES, series);
+    }
+    
+    for tier in ["i3", "i5", "i7", "i9"] {
+        let number = model_number_after(&model, &format!("{}-", tier))
+            .or_else(|| model_number_after(&model, &format!("{} cpu ", tier)));
+        if let Some(number) = number {
+            let generation = match number.len() {
+                5 => number[..2].parse().ok()?,
+                4 if number.starts_with('1') => return lookup(INTEL_CORE_MOBILE_GENERATIONS, number[..2].parse().ok()?),
+                4 => number[..1].parse().ok()?,
+                3 => 1,
+                _ => return None,
+            };
+            return lookup(INTEL_CORE_GENERATIONS, generation);
+        }
+    }
+    
+    if model.contains("xeon") {
+        if let Some(pos) = model.find(" v") {
+            if let Some(revision) = model_number_after(&model[pos..], " v") {
+                return lookup(INTEL_XEON_REVISIONS, revision.parse().ok()?);
+            }
+        }
+        for marker in ["platinum ", "gold ", "silver ", "bronze "] {
+            if let Some(number) = model_number_after(&model, marker) {
+                let generation = number.chars().nth(1)?.to_digit(10)?;
+                return lookup(INTEL_XEON_SCALABLE, generation);
+            }
+        }
+    }
+    
+    if model.contains("epyc") {
+        let number = model_number_after(&model, "epyc ")?;
+        let series = number.chars().next()?.to_digit(10)?;
+        let generation = number.chars().last()?.to_digit(10)?;
+        return AMD_EPYC_MODELS.iter()
+            .find(|(s, g, _)| *s == series && *g == generation)
+            .map(|(_, _, year)| *year)
+            .or_else(|| lookup(AMD_EPYC_GENERATIONS, generation));
+    }
+    
+    if model.contains("ryzen") {
+        let number = model.split_whitespace()
+            .find(|part| part.chars().take(4).filter(|c| c.is_ascii_digit()).count() == 4)?;
+        let series = number.chars().next()?.to_digit(10)?;
+        return lookup(AMD_RYZEN_SERIES, series);
+    }
     
     None
 }
+
+pub fn gpu_release_year(model: &str) -> Option<u32> {
+    let model = model.to_lowercase();
+    
+    if let Some(year) = lookup_prefix(NVIDIA_NAMED, &model)
+        .or_else(|| lookup_prefix(AMD_RADEON_NAMED, &model))
+        .or_else(|| lookup_prefix(INTEL_GPU_SERIES, &model))
+    {
+        return Some(year);
+    }
+    
+    for (marker, table) in [("rtx ", NVIDIA_RTX_SERIES), ("gtx ", NVIDIA_GTX_SERIES)] {
+        if let Some(number) = model_number_after(&model, marker) {
+            let series = match number.len() {
+                4 => number[..2].parse().ok()?,
+                3 => number[..1].parse().ok()?,
+                _ => return None,
+            };
+            return lookup(table, series);
+        }
+    }
+    
+    let number = model_number_after(&model, "rx ")?;
+    let series = number.chars().next()?.to_digit(10)?;
+    match number.len() {
+        4 => lookup(AMD_RADEON_RDNA_SERIES, series),
+        3 => lookup(AMD_RADEON_POLARIS_SERIES, series),
+        _ => None,
+    }
+}
+
+fn format_age(year: u32, current_year: u32) -> String {
+    let age = current_year.saturating_sub(year);
+    match age {
+        0 => "this year".to_string(),
+        1 => "~1 year".to_string(),
+        _ => format!("~{} years", age),
+    }
+}
+
+pub fn current_year() -> u32 {
+    chrono::Local::now().year() as u32
+}
+
+pub fn estimate_hardware_age(cpu_model: &str, gpu_models: &[&str], bios_year: Option<u32>, current_year: u32) -> Option<String> {
+    let gpu_year = gpu_models.iter().find_map(|model| gpu_release_year(model));
+    
+    let mut parts = Vec::new();
+    
+    if let Some(year) = cpu_release_year(cpu_model) {
+        parts.push(format!("CPU released {} ({})", year, format_age(year, current_year)));
+    }
+    
+    if let Some(year) = gpu_year {
+        parts.push(format!("GPU released {} ({})", year, format_age(year, current_year)));
+    }
+    
+    if parts.is_empty() {
+        let year = bios_year?;
+        parts.push(format!("firmware dated {} ({})", year, format_age(year, current_year)));
+    }
+    
+    Some(parts.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn intel_core_years() {
+        assert_eq!(cpu_release_year("Intel(R) Core(TM) i7-8700K CPU @ 3.70GHz"), Some(2017));
+        assert_eq!(cpu_release_year("11th Gen Intel(R) Core(TM) i7-1165G7 @ 2.80GHz"), Some(2020));
+        assert_eq!(cpu_release_year("11th Gen Intel(R) Core(TM) i9-11900K @ 3.50GHz"), Some(2021));
+        assert_eq!(cpu_release_year("Intel(R) Core(TM) i9-13900K"), Some(2022));
+        assert_eq!(cpu_release_year("Intel(R) Core(TM) i5-750"), Some(2009));
+        assert_eq!(cpu_release_year("Intel(R) Core(TM) i7 CPU         860  @ 2.80GHz"), Some(2009));
+        assert_eq!(cpu_release_year("Intel(R) Core(TM)2 Duo CPU E8400 @ 3.00GHz"), Some(2006));
+        assert_eq!(cpu_release_year("Intel(R) Core(TM) Ultra 7 155H"), Some(2023));
+    }
+    
+    #[test]
+    fn intel_xeon_years() {
+        assert_eq!(cpu_release_year("Intel(R) Xeon(R) CPU E5-2680 v4 @ 2.40GHz"), Some(2016));
+        assert_eq!(cpu_release_year("Intel(R) Xeon(R) Gold 6338 CPU @ 2.00GHz"), Some(2021));
+    }
+    
+    #[test]
+    fn amd_cpu_years() {
+        assert_eq!(cpu_release_year("AMD Ryzen 7 5800X 8-Core Processor"), Some(2020));
+        assert_eq!(cpu_release_year("AMD Ryzen 9 7950X 16-Core Processor"), Some(2022));
+        assert_eq!(cpu_release_year("AMD EPYC 7763 64-Core Processor"), Some(2021));
+        assert_eq!(cpu_release_year("AMD EPYC 9654 96-Core Processor"), Some(2022));
+        assert_eq!(cpu_release_year("AMD EPYC 4564P 16-Core Processor"), Some(2024));
+        assert_eq!(cpu_release_year("AMD Ryzen Threadripper 7980X 64-Cores"), Some(2023));
+        assert_eq!(cpu_release_year("AMD Ryzen Threadripper PRO 5995WX 64-Cores"), Some(2022));
+        assert_eq!(cpu_release_year("AMD FX(tm)-8350 Eight-Core Processor"), Some(2011));
+    }
+    
+    #[test]
+    fn other_cpu_years() {
+        assert_eq!(cpu_release_year("Apple M2 Pro"), Some(2022));
+        assert_eq!(cpu_release_year("Cortex-A72"), Some(2015));
+        assert_eq!(cpu_release_year("Unknown CPU"), None);
+    }
+    
+    #[test]
+    fn nvidia_years() {
+        assert_eq!(gpu_release_year("NVIDIA GeForce GTX 1080"), Some(2016));
+        assert_eq!(gpu_release_year("NVIDIA GeForce GTX 980 Ti"), Some(2014));
+        assert_eq!(gpu_release_year("NVIDIA GeForce GTX 1660 SUPER"), Some(2019));
+        assert_eq!(gpu_release_year("NVIDIA GeForce RTX 4090"), Some(2022));
+        assert_eq!(gpu_release_year("NVIDIA Quadro RTX 4000"), Some(2018));
+        assert_eq!(gpu_release_year("NVIDIA RTX A4000"), Some(2021));
+        assert_eq!(gpu_release_year("NVIDIA RTX 4000 SFF Ada Generation"), Some(2023));
+    }
+    
+    #[test]
+    fn amd_and_intel_gpu_years() {
+        assert_eq!(gpu_release_year("AMD Radeon RX 6800"), Some(2020));
+        assert_eq!(gpu_release_year("AMD Radeon RX 580"), Some(2017));
+        assert_eq!(gpu_release_year("AMD Radeon RX Vega 56"), Some(2017));
+        assert_eq!(gpu_release_year("Intel Arc A770"), Some(2022));
+        assert_eq!(gpu_release_year("Intel UHD Graphics 620"), Some(2017));
+    }
+    
+    #[test]
+    fn estimates_age_from_current_year() {
+        assert_eq!(
+            estimate_hardware_age("AMD Ryzen 7 5800X", &["AMD Radeon RX 6800"], None, 2025),
+            Some("CPU released 2020 (~5 years), GPU released 2020 (~5 years)".to_string())
+        );
+        assert_eq!(
+            estimate_hardware_age("Unknown CPU", &[], Some(2024), 2025),
+            Some("firmware dated 2024 (~1 year)".to_string())
+        );
+        assert_eq!(estimate_hardware_age("Unknown CPU", &[], None, 2025), None);
+    }
+}
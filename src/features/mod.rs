@@ -29,3 +29,5 @@ pub mod benchmark;
 pub mod processes;
 pub mod colors_preview;
 pub mod hardware_age;
+pub mod watch;
+pub mod diff;
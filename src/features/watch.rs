@@ -0,0 +1,75 @@
+/*
+* Copyright (C) 2026 compiledkernel-idk <https://github.com/compiledkernel-idk>
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program. If not, see <https://www.gnu.org/licenses/>
+*/
+
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+use sysinfo::System;
+use crate::cli::Args;
+use crate::modules::SystemInfo;
+use crate::output::{self, colors::Theme, terminal, Logo, Templates};
+
+const POLL_STEP: Duration = Duration::from_millis(50);
+
+static STOP: AtomicBool = AtomicBool::new(false);
+
+extern "C" fn request_stop(_signal: libc::c_int) {
+    STOP.store(true, Ordering::SeqCst);
+}
+
+pub fn run(logo: &Logo, info: &mut SystemInfo, sys: &mut System, theme: &Theme, args: &Args, templates: &Templates, interval: Duration) {
+    unsafe {
+        libc::signal(libc::SIGINT, request_stop as *const () as libc::sighandler_t);
+        libc::signal(libc::SIGTERM, request_stop as *const () as libc::sighandler_t);
+    }
+    
+    let interactive = terminal::stdout_is_tty() && !args.pipe;
+    let clear_images = if matches!(logo, Logo::Image(_)) { "\x1b_Ga=d,q=2\x1b\\" } else { "" };
+    let mut stdout = io::stdout();
+    let mut last_tick = Instant::now();
+    
+    if interactive {
+        let _ = write!(stdout, "\x1b[?1049h\x1b[?25l");
+    }
+    
+    while !STOP.load(Ordering::SeqCst) {
+        let frame = output::render_to_string(logo, info, theme, args, templates);
+        let written = if interactive {
+            write!(stdout, "{}\x1b[H{}\x1b[J", clear_images, frame.replace('\n', "\x1b[K\n"))
+        } else {
+            write!(stdout, "{}{}", frame, if args.oneline { "" } else { "\n" })
+        };
+        if written.and_then(|_| stdout.flush()).is_err() {
+            break;
+        }
+        
+        let deadline = Instant::now() + interval;
+        while !STOP.load(Ordering::SeqCst) && Instant::now() < deadline {
+            thread::sleep(POLL_STEP.min(deadline.saturating_duration_since(Instant::now())));
+        }
+        
+        let now = Instant::now();
+        info.refresh(sys, now - last_tick);
+        last_tick = now;
+    }
+    
+    if interactive {
+        let _ = write!(stdout, "{}\x1b[?25h\x1b[?1049l", clear_images);
+        let _ = stdout.flush();
+    }
+}
@@ -16,63 +16,327 @@
 */
 
 use std::collections::HashMap;
-use colored::Colorize;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use colored::{Color, Colorize};
+use crate::config;
+use unicode_width::UnicodeWidthStr;
+use crate::output::colors::{parse_color, Theme};
 
-pub fn get_logo(name: &str) -> Vec<String> {
-    let normalized = name.to_lowercase().replace(' ', "_");
+pub fn get_logo(name: &str, palette: &[Color], theme: &Theme) -> Vec<String> {
+    let source = if name == "-" || is_logo_path(name) {
+        read_logo_file(name).map_err(|error| eprintln!("{}", error)).ok()
+    } else {
+        load_logo_source(name)
+    };
     
-    LOGOS.get(normalized.as_str())
-        .or_else(|| LOGOS.get(name))
-        .or_else(|| LOGOS.get("linux"))
-        .map(|logo| parse_logo(logo))
+    source
+        .or_else(|| LOGOS.get("linux").map(|logo| logo.to_string()))
+        .map(|logo| parse_logo(&logo, palette, theme))
         .unwrap_or_else(|| vec!["No logo found".to_string()])
 }
 
-fn parse_logo(logo: &str) -> Vec<String> {
-    let lines: Vec<String> = logo.lines().map(|line| {
-        parse_color_line(line)
-    }).collect();
+pub fn is_logo_path(name: &str) -> bool {
+    name.contains('/') || Path::new(name).extension().is_some()
+}
+
+fn read_logo_file(name: &str) -> Result<String, String> {
+    if name == "-" {
+        let mut content = String::new();
+        io::stdin().read_to_string(&mut content)
+            .map_err(|e| format!("Cannot read logo from stdin: {}", e))?;
+        if content.trim().is_empty() {
+            return Err("No logo received on stdin".to_string());
+        }
+        return Ok(content);
+    }
+    
+    fs::read_to_string(name).map_err(|e| format!("Cannot read logo '{}': {}", name, e))
+}
+
+fn load_logo_source(name: &str) -> Option<String> {
+    let normalized = name.to_lowercase().replace(' ', "_");
+    fs::read_to_string(user_logo_path(&normalized)).ok()
+        .or_else(|| LOGOS.get(normalized.as_str()).map(|logo| logo.to_string()))
+        .or_else(|| LOGOS.get(name).map(|logo| logo.to_string()))
+}
+
+pub fn logos_dir() -> PathBuf {
+    config::config_dir().join("logos")
+}
+
+fn user_logo_path(name: &str) -> PathBuf {
+    logos_dir().join(format!("{}.txt", name))
+}
+
+pub fn logo_names() -> Vec<(String, bool)> {
+    let mut user: Vec<String> = fs::read_dir(logos_dir())
+        .map(|entries| {
+            entries.flatten()
+                .map(|entry| entry.path())
+                .filter(|path| path.extension().map(|e| e == "txt").unwrap_or(false))
+                .filter_map(|path| path.file_stem().map(|s| s.to_string_lossy().to_string()))
+                .collect()
+        })
+        .unwrap_or_default();
+    user.sort();
+    
+    let mut builtin: Vec<String> = LOGOS.keys()
+        .map(|name| name.to_string())
+        .filter(|name| !user.contains(name))
+        .collect();
+    builtin.sort();
+    
+    user.into_iter().map(|name| (name, true))
+        .chain(builtin.into_iter().map(|name| (name, false)))
+        .collect()
+}
+
+pub fn show_logo_list() {
+    println!("{}", "Available Logos".bright_cyan().bold());
+    println!("{}", "─".repeat(40).bright_black());
+    
+    for (name, user) in logo_names() {
+        if user {
+            println!("{} {}", name, "(user)".bright_black());
+        } else {
+            println!("{}", name);
+        }
+    }
+}
+
+pub fn resolve_logo_name(candidates: &[String]) -> String {
+    let normalize = |name: &str| name.to_lowercase().replace(' ', "_");
+    
+    candidates.iter()
+        .map(|name| normalize(name))
+        .find(|name| has_logo(name))
+        .or_else(|| {
+            candidates.iter()
+                .filter_map(|name| parent_distro(&normalize(name)))
+                .find(|parent| has_logo(parent))
+                .map(|parent| parent.to_string())
+        })
+        .or_else(|| {
+            candidates.iter()
+                .filter_map(|name| normalize(name).split(['-', '_']).next().map(|s| s.to_string()))
+                .find(|base| has_logo(base))
+        })
+        .unwrap_or_else(|| "linux".to_string())
+}
+
+fn parent_distro(id: &str) -> Option<&'static str> {
+    DERIVATIVES.iter()
+        .find(|(derivative, _)| *derivative == id)
+        .map(|(_, parent)| *parent)
+}
+
+pub fn logo_colors(name: &str) -> Vec<Color> {
+    let normalized = name.to_lowercase().replace(' ', "_");
+    let base = normalized.strip_suffix("_small").unwrap_or(&normalized);
+    let stripped = base.trim_end_matches(|c: char| c.is_ascii_digit());
+    
+    [normalized.as_str(), base, stripped].iter()
+        .find_map(|candidate| LOGO_COLORS.iter().find(|(logo, _)| logo == candidate))
+        .map(|(_, colors)| colors.to_vec())
+        .unwrap_or_else(|| vec![Color::White, Color::BrightBlack, Color::Yellow])
+}
+
+pub fn logo_palette(name: &str, overrides: &[(usize, Color)]) -> Vec<Color> {
+    let mut palette = logo_colors(name);
+    
+    for (index, color) in overrides {
+        if *index == 0 || *index > 9 {
+            continue;
+        }
+        if palette.len() < *index {
+            let filler = palette.last().copied().unwrap_or(Color::White);
+            palette.resize(*index, filler);
+        }
+        palette[index - 1] = *color;
+    }
+    
+    palette
+}
+
+pub fn parse_color_overrides(spec: &str) -> Vec<(usize, Color)> {
+    spec.split(',')
+        .filter_map(|entry| {
+            let (index, color) = entry.split_once('=')?;
+            Some((index.trim().parse().ok()?, parse_color(color)?))
+        })
+        .collect()
+}
+
+pub fn has_logo(name: &str) -> bool {
+    let normalized = name.to_lowercase().replace(' ', "_");
+    LOGOS.contains_key(normalized.as_str())
+        || LOGOS.contains_key(name)
+        || user_logo_path(&normalized).is_file()
+}
+
+fn parse_logo(logo: &str, palette: &[Color], theme: &Theme) -> Vec<String> {
+    let mut current = palette.first().copied();
     
-    lines
+    logo.lines()
+        .map(|line| {
+            parse_color_line(line, palette, &mut current).into_iter()
+                .map(|(color, run)| match color {
+                    Some(color) => theme.paint(&run, color),
+                    None => run,
+                })
+                .collect()
+        })
+        .collect()
 }
 
-fn parse_color_line(line: &str) -> String {
-    let mut result = String::new();
+fn parse_color_line(line: &str, palette: &[Color], current: &mut Option<Color>) -> Vec<(Option<Color>, String)> {
+    let mut runs = Vec::new();
+    let mut run = String::new();
     let mut chars = line.chars().peekable();
-    let mut current_color = 0;
     
     while let Some(ch) = chars.next() {
         if ch == '$' {
-            if let Some(&next_ch) = chars.peek() {
-                if next_ch.is_ascii_digit() {
-                    chars.next(); 
-                    current_color = next_ch.to_digit(10).unwrap_or(0) as usize;
+            match chars.peek().copied() {
+                Some('$') => {
+                    chars.next();
+                    run.push('$');
                     continue;
                 }
+                Some(digit) if digit.is_ascii_digit() => {
+                    chars.next();
+                    flush_run(&mut runs, &mut run, *current);
+                    *current = digit.to_digit(10)
+                        .filter(|&i| i > 0)
+                        .and_then(|i| palette.get(i as usize - 1).copied());
+                    continue;
+                }
+                Some('{') => {
+                    let spec: String = chars.clone().skip(1).take_while(|&c| c != '}').collect();
+                    let closed = chars.clone().nth(spec.chars().count() + 1) == Some('}');
+                    if let Some(color) = parse_color(&spec).filter(|_| closed) {
+                        chars.nth(spec.chars().count() + 1);
+                        flush_run(&mut runs, &mut run, *current);
+                        *current = Some(color);
+                        continue;
+                    }
+                }
+                _ => {}
             }
         }
         
-        let colored_char = apply_color(ch, current_color);
-        result.push_str(&colored_char);
+        run.push(ch);
     }
     
-    result
-}
-
-fn apply_color(ch: char, color_idx: usize) -> String {
-    let s = ch.to_string();
-    match color_idx {
-        1 => s.bright_blue().to_string(),
-        2 => s.cyan().to_string(),
-        3 => s.bright_cyan().to_string(),
-        4 => s.green().to_string(),
-        5 => s.yellow().to_string(),
-        6 => s.red().to_string(),
-        7 => s.magenta().to_string(),
-        _ => s,
+    flush_run(&mut runs, &mut run, *current);
+    runs
+}
+
+fn flush_run(runs: &mut Vec<(Option<Color>, String)>, run: &mut String, color: Option<Color>) {
+    if !run.is_empty() {
+        runs.push((color, std::mem::take(run)));
     }
 }
 
+fn logo_width(name: &str) -> usize {
+    let Some(source) = load_logo_source(name) else {
+        return 0;
+    };
+    
+    let mut current = None;
+    source.lines()
+        .map(|line| {
+            parse_color_line(line, &[], &mut current).iter()
+                .map(|(_, run)| run.width())
+                .sum::<usize>()
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+pub fn select_variant(name: &str, small: bool, max_width: Option<usize>) -> String {
+    if name == "-" || is_logo_path(name) {
+        return name.to_string();
+    }
+    
+    let normalized = name.to_lowercase().replace(' ', "_");
+    let small_name = format!("{}_small", normalized);
+    if normalized.ends_with("_small") || !has_logo(&small_name) {
+        return normalized;
+    }
+    
+    let too_wide = max_width.map(|width| logo_width(&normalized) > width).unwrap_or(false);
+    if small || too_wide {
+        small_name
+    } else {
+        normalized
+    }
+}
+
+const LOGO_COLORS: &[(&str, &[Color])] = &[
+    ("linux", &[Color::White, Color::BrightBlack, Color::Yellow]),
+    ("arch", &[Color::Cyan, Color::Cyan]),
+    ("manjaro", &[Color::Green]),
+    ("endeavouros", &[Color::Magenta, Color::Red, Color::Blue]),
+    ("garuda", &[Color::Red]),
+    ("cachyos", &[Color::Cyan, Color::Green, Color::Black]),
+    ("artix", &[Color::Cyan]),
+    ("debian", &[Color::Red, Color::White]),
+    ("ubuntu", &[Color::Red, Color::White]),
+    ("ubuntu_old", &[Color::Red, Color::BrightRed, Color::Yellow]),
+    ("mint", &[Color::Green, Color::White]),
+    ("linuxmint", &[Color::Green, Color::White]),
+    ("pop", &[Color::Cyan, Color::White]),
+    ("pop_os", &[Color::Cyan, Color::White]),
+    ("elementary", &[Color::White]),
+    ("kali", &[Color::Blue, Color::White]),
+    ("mxlinux", &[Color::Blue, Color::White]),
+    ("fedora", &[Color::Blue, Color::White]),
+    ("rhel", &[Color::Red]),
+    ("centos", &[Color::Yellow, Color::Green, Color::Blue, Color::Magenta, Color::White]),
+    ("rocky", &[Color::Green]),
+    ("almalinux", &[Color::Red, Color::Yellow, Color::Blue, Color::Green, Color::Cyan]),
+    ("opensuse", &[Color::Green, Color::White]),
+    ("opensuse-tumbleweed", &[Color::Green, Color::White]),
+    ("opensuse-leap", &[Color::Green, Color::White]),
+    ("gentoo", &[Color::Magenta, Color::White]),
+    ("nixos", &[Color::Blue, Color::Cyan]),
+    ("void", &[Color::Green, Color::Black]),
+    ("alpine", &[Color::Blue, Color::White]),
+    ("slackware", &[Color::Blue, Color::White]),
+];
+
+const DERIVATIVES: &[(&str, &str)] = &[
+    ("arcolinux", "arch"),
+    ("archcraft", "arch"),
+    ("blendos", "arch"),
+    ("rebornos", "arch"),
+    ("steamos", "arch"),
+    ("devuan", "debian"),
+    ("deepin", "debian"),
+    ("parrot", "debian"),
+    ("pureos", "debian"),
+    ("raspbian", "debian"),
+    ("kubuntu", "ubuntu"),
+    ("lubuntu", "ubuntu"),
+    ("neon", "ubuntu"),
+    ("xubuntu", "ubuntu"),
+    ("zorin", "ubuntu"),
+    ("lmde", "mint"),
+    ("bazzite", "fedora"),
+    ("nobara", "fedora"),
+    ("ultramarine", "fedora"),
+    ("eurolinux", "rhel"),
+    ("ol", "rhel"),
+    ("scientific", "rhel"),
+    ("calculate", "gentoo"),
+    ("funtoo", "gentoo"),
+    ("postmarketos", "alpine"),
+    ("sled", "opensuse"),
+    ("sles", "opensuse"),
+];
+
 lazy_static::lazy_static! {
     static ref LOGOS: HashMap<&'static str, &'static str> = {
         let mut m = HashMap::new();
@@ -112,6 +376,42 @@ lazy_static::lazy_static! {
         m.insert("alpine", include_str!("ascii/alpine.txt"));
         m.insert("slackware", include_str!("ascii/slackware.txt"));
         
+        m.insert("linux_small", include_str!("ascii/linux_small.txt"));
+        m.insert("arch_small", include_str!("ascii/arch_small.txt"));
+        m.insert("arch2", include_str!("ascii/arch2.txt"));
+        m.insert("manjaro_small", include_str!("ascii/manjaro_small.txt"));
+        m.insert("endeavouros_small", include_str!("ascii/endeavouros_small.txt"));
+        m.insert("garuda_small", include_str!("ascii/garuda_small.txt"));
+        m.insert("cachyos_small", include_str!("ascii/cachyos_small.txt"));
+        m.insert("artix_small", include_str!("ascii/artix_small.txt"));
+        
+        m.insert("debian_small", include_str!("ascii/debian_small.txt"));
+        m.insert("ubuntu_small", include_str!("ascii/ubuntu_small.txt"));
+        m.insert("ubuntu_old", include_str!("ascii/ubuntu_old.txt"));
+        m.insert("mint_small", include_str!("ascii/mint_small.txt"));
+        m.insert("linuxmint_small", include_str!("ascii/mint_small.txt"));
+        m.insert("pop_small", include_str!("ascii/pop_small.txt"));
+        m.insert("pop_os_small", include_str!("ascii/pop_small.txt"));
+        m.insert("elementary_small", include_str!("ascii/elementary_small.txt"));
+        m.insert("kali_small", include_str!("ascii/kali_small.txt"));
+        m.insert("mxlinux_small", include_str!("ascii/mxlinux_small.txt"));
+        
+        m.insert("fedora_small", include_str!("ascii/fedora_small.txt"));
+        m.insert("rhel_small", include_str!("ascii/rhel_small.txt"));
+        m.insert("centos_small", include_str!("ascii/centos_small.txt"));
+        m.insert("rocky_small", include_str!("ascii/rocky_small.txt"));
+        m.insert("almalinux_small", include_str!("ascii/almalinux_small.txt"));
+        
+        m.insert("opensuse_small", include_str!("ascii/opensuse_small.txt"));
+        m.insert("opensuse-tumbleweed_small", include_str!("ascii/opensuse_small.txt"));
+        m.insert("opensuse-leap_small", include_str!("ascii/opensuse_small.txt"));
+        
+        m.insert("gentoo_small", include_str!("ascii/gentoo_small.txt"));
+        m.insert("nixos_small", include_str!("ascii/nixos_small.txt"));
+        m.insert("void_small", include_str!("ascii/void_small.txt"));
+        m.insert("alpine_small", include_str!("ascii/alpine_small.txt"));
+        m.insert("slackware_small", include_str!("ascii/slackware_small.txt"));
+        
         m
     };
 }
@@ -25,29 +25,159 @@ along with this program. If not, see https://www.gnu.org/licenses/
 */
 
 mod cli;
+mod config;
 mod modules;
 mod logos;
 mod output;
 mod features;
 
-use cli::Args;
+use cli::{Args, Command};
 use clap::Parser;
+use std::time::Duration;
+use sysinfo::System;
+use output::{Logo, LogoPosition, Templates};
+use output::structured::OutputFormat;
 
 fn main() {
+    unsafe { libc::signal(libc::SIGPIPE, libc::SIG_DFL) };
+    
     let args = Args::parse();
     
-    let info = modules::collect_all_info();
+    colored::control::set_override(output::colors::should_colorize(args.color_mode(), args.pipe));
+    
+    if args.list_themes {
+        features::colors_preview::show_themes(output::colors::palette_query_enabled(args.query_palette));
+        return;
+    }
+    
+    if args.list_logos {
+        logos::show_logo_list();
+        return;
+    }
+    
+    let output_format = match args.format.as_deref() {
+        Some(name) => match OutputFormat::from_name(name) {
+            Some(format) => Some(format),
+            None => {
+                eprintln!("Unknown format '{}'. Available: {}", name, OutputFormat::NAMES.join(", "));
+                std::process::exit(2);
+            }
+        },
+        None => args.json.then_some(OutputFormat::Json),
+    };
+    
+    if let Some(Command::Diff { old, new }) = &args.command {
+        let snapshots = features::diff::load_snapshot(old)
+            .and_then(|old_values| Ok((old_values, features::diff::load_snapshot(new)?)));
+        match snapshots {
+            Ok((old_values, new_values)) => run_diff(&args, output_format, old, &old_values, new, &new_values),
+            Err(error) => {
+                eprintln!("{}", error);
+                std::process::exit(2);
+            }
+        }
+    }
     
-    let logo = if args.no_logo {
-        Vec::new()
+    let mut sys = System::new_all();
+    let mut info = modules::collect_all_info(&mut sys);
+    
+    if let Some(path) = args.save.as_deref() {
+        if let Err(error) = features::diff::save_snapshot(&info, path) {
+            eprintln!("{}", error);
+            std::process::exit(1);
+        }
+    }
+    
+    if let Some(path) = args.diff_with.as_deref() {
+        match features::diff::load_snapshot(path) {
+            Ok(old_values) => {
+                let current = features::diff::current_snapshot(&info);
+                run_diff(&args, output_format, path, &old_values, "current", &current);
+            }
+            Err(error) => {
+                eprintln!("{}", error);
+                std::process::exit(2);
+            }
+        }
+    }
+    
+    if let Some(query) = args.get.as_deref() {
+        if !output::plain::print_field(&info, query) {
+            std::process::exit(1);
+        }
+        return;
+    }
+    
+    if args.key_value {
+        output::plain::render_key_value(&info);
+        return;
+    }
+    
+    if let Some(format) = output_format {
+        output::structured::render(&info, format);
+        return;
+    }
+    
+    let logo_name = match args.logo.as_deref() {
+        Some(logo_name) => logo_name.to_string(),
+        None => logos::resolve_logo_name(&info.os.logo_candidates()),
+    };
+    let position = args.logo_position;
+    let columns = output::terminal::width().filter(|_| !args.pipe);
+    let small = args.small || columns.zip(args.small_below).is_some_and(|(width, threshold)| width < threshold);
+    let hide_logo = columns.is_some_and(|width| width < args.hide_logo_below.unwrap_or(output::MIN_INFO_WIDTH));
+    let max_logo_width = columns.map(|width| match position {
+        Some(LogoPosition::Top) => width.saturating_sub(args.logo_padding_left.unwrap_or(0)),
+        _ => width.saturating_sub(output::MIN_INFO_WIDTH + args.logo_padding_left.unwrap_or(0) + args.logo_padding_right.unwrap_or(2)),
+    });
+    let logo_name = logos::select_variant(&logo_name, small, max_logo_width);
+    
+    let overrides = args.logo_color.as_deref()
+        .map(logos::parse_color_overrides)
+        .unwrap_or_default();
+    let palette = logos::logo_palette(&logo_name, &overrides);
+    
+    let theme = match output::colors::resolve_theme(
+        args.theme_name(),
+        &palette,
+        output::colors::palette_query_enabled(args.query_palette),
+    ) {
+        Ok(theme) => theme,
+        Err(error) => {
+            eprintln!("{}", error);
+            std::process::exit(2);
+        }
+    };
+    
+    let templates = Templates::load(&args);
+    
+    let logo = if args.no_logo || args.oneline || hide_logo || position == Some(LogoPosition::Hidden) {
+        Logo::Text(Vec::new())
     } else {
-        let logo_name = args.logo.as_deref().unwrap_or(&info.os.distro_id);
-        logos::get_logo(logo_name)
+        args.image.as_deref()
+            .filter(|_| !args.pipe && colored::control::SHOULD_COLORIZE.should_colorize())
+            .and_then(|path| output::image::load_logo(
+                path,
+                args.image_protocol,
+                args.image_width.unwrap_or(output::image::DEFAULT_COLUMNS),
+                theme.depth,
+            ).map_err(|error| eprintln!("{}", error)).ok())
+            .unwrap_or_else(|| Logo::Text(logos::get_logo(&logo_name, &palette, &theme)))
     };
     
-    let theme = output::colors::get_theme(&args.color.as_deref().unwrap_or("default"));
+    if let Some(interval) = args.watch {
+        let interval = match Duration::try_from_secs_f64(interval) {
+            Ok(interval) => interval.max(Duration::from_millis(100)),
+            Err(_) => {
+                eprintln!("Invalid --watch interval '{}'", interval);
+                std::process::exit(2);
+            }
+        };
+        features::watch::run(&logo, &mut info, &mut sys, &theme, &args, &templates, interval);
+        return;
+    }
     
-    output::render(&logo, &info, &theme, &args);
+    output::render(&logo, &info, &theme, &args, &templates);
     
     if args.benchmark {
         println!();
@@ -64,3 +194,29 @@ fn main() {
         features::colors_preview::show_palette();
     }
 }
+
+fn run_diff(args: &Args, format: Option<OutputFormat>, old_name: &str, old: &[(String, String)], new_name: &str, new: &[(String, String)]) -> ! {
+    let json = match format {
+        None => false,
+        Some(OutputFormat::Json) => true,
+        Some(_) => {
+            eprintln!("Diff output supports only the json format");
+            std::process::exit(2);
+        }
+    };
+    
+    let theme = match output::colors::resolve_theme(
+        args.theme_name(),
+        &[],
+        !json && output::colors::palette_query_enabled(args.query_palette),
+    ) {
+        Ok(theme) => theme,
+        Err(error) => {
+            eprintln!("{}", error);
+            std::process::exit(2);
+        }
+    };
+    
+    let changed = features::diff::run(old_name, old, new_name, new, &theme, args.diff_all, json);
+    std::process::exit(if changed { 1 } else { 0 });
+}
@@ -15,18 +15,35 @@
 * along with this program. If not, see <https://www.gnu.org/licenses/>
 */
 
+use std::fs;
+use std::path::Path;
+use super::Fields;
+
+const POWER_SUPPLY_PATH: &str = "/sys/class/power_supply";
+
 #[derive(Debug, Clone)]
 pub struct BatteryInfo {
     pub batteries: Vec<Battery>,
+    pub peripherals: Vec<Battery>,
+    pub ac_online: Option<bool>,
     pub has_battery: bool,
 }
 
 #[derive(Debug, Clone)]
 pub struct Battery {
     pub name: String,
+    pub manufacturer: Option<String>,
+    pub model: Option<String>,
+    pub technology: Option<String>,
     pub percentage: f32,
     pub state: BatteryState,
     pub health: Option<f32>,
+    pub cycle_count: Option<u32>,
+    pub energy_full_wh: Option<f32>,
+    pub energy_full_design_wh: Option<f32>,
+    pub power_watts: Option<f32>,
+    pub charge_start_threshold: Option<u8>,
+    pub charge_end_threshold: Option<u8>,
     pub time_remaining: Option<String>,
 }
 
@@ -41,72 +58,183 @@ pub enum BatteryState {
 
 pub fn get_info() -> BatteryInfo {
     let mut batteries = Vec::new();
+    let mut peripherals = Vec::new();
+    let mut ac_online: Option<bool> = None;
     
-    if let Ok(manager) = battery::Manager::new() {
-        if let Ok(bats) = manager.batteries() {
-            for (idx, bat_result) in bats.enumerate() {
-                if let Ok(bat) = bat_result {
-                    let percentage = bat.state_of_charge().get::<battery::units::ratio::percent>();
-                    
-                    let state = match bat.state() {
-                        battery::State::Charging => BatteryState::Charging,
-                        battery::State::Discharging => BatteryState::Discharging,
-                        battery::State::Full => BatteryState::Full,
-                        battery::State::Empty => BatteryState::Discharging,
-                        _ => BatteryState::Unknown,
+    if let Ok(entries) = fs::read_dir(POWER_SUPPLY_PATH) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let name = entry.file_name().to_string_lossy().to_string();
+            let supply_type = read_string(&path, "type").unwrap_or_default();
+            
+            match supply_type.as_str() {
+                "Mains" | "USB" => {
+                    if let Some(online) = read_number::<u8>(&path, "online") {
+                        ac_online = Some(ac_online.unwrap_or(false) || online == 1);
+                    }
+                }
+                "Battery" => {
+                    let Some(battery) = read_battery(&path, name) else {
+                        continue;
                     };
                     
-                    let health = Some(bat.state_of_health().get::<battery::units::ratio::percent>());
-                    
-                    let time_remaining = get_time_remaining(&bat);
-                    
-                    batteries.push(Battery {
-                        name: format!("BAT{}", idx),
-                        percentage,
-                        state,
-                        health,
-                        time_remaining,
-                    });
+                    if read_string(&path, "scope").as_deref() == Some("Device") {
+                        peripherals.push(battery);
+                    } else {
+                        batteries.push(battery);
+                    }
                 }
+                _ => {}
             }
         }
     }
     
+    batteries.sort_by(|a, b| a.name.cmp(&b.name));
+    peripherals.sort_by(|a, b| a.name.cmp(&b.name));
+    
     let has_battery = !batteries.is_empty();
     
     BatteryInfo {
         batteries,
+        peripherals,
+        ac_online,
         has_battery,
     }
 }
 
-fn get_time_remaining(bat: &battery::Battery) -> Option<String> {
-    let time = if bat.state() == battery::State::Charging {
-        bat.time_to_full()
-    } else {
-        bat.time_to_empty()
+fn read_string(path: &Path, file: &str) -> Option<String> {
+    fs::read_to_string(path.join(file))
+        .ok()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
+fn read_number<T: std::str::FromStr>(path: &Path, file: &str) -> Option<T> {
+    read_string(path, file)?.parse().ok()
+}
+
+fn read_micro(path: &Path, file: &str) -> Option<f32> {
+    read_number::<i64>(path, file).map(|v| v.unsigned_abs() as f32 / 1_000_000.0)
+}
+
+fn read_battery(path: &Path, name: String) -> Option<Battery> {
+    if read_number::<u8>(path, "present") == Some(0) {
+        return None;
+    }
+    
+    let voltage = read_micro(path, "voltage_min_design")
+        .or_else(|| read_micro(path, "voltage_now"));
+    
+    let energy = |energy_file: &str, charge_file: &str| -> Option<f32> {
+        read_micro(path, energy_file)
+            .or_else(|| Some(read_micro(path, charge_file)? * voltage?))
     };
     
-    time.map(|t| {
-        let secs = t.get::<battery::units::time::second>() as u64;
-        let hours = secs / 3600;
-        let mins = (secs % 3600) / 60;
-        
-        if hours > 0 {
-            format!("{}h {}m", hours, mins)
-        } else {
-            format!("{}m", mins)
-        }
+    let energy_now = energy("energy_now", "charge_now");
+    let energy_full = energy("energy_full", "charge_full");
+    let energy_full_design = energy("energy_full_design", "charge_full_design");
+    
+    let power_watts = read_micro(path, "power_now")
+        .or_else(|| Some(read_micro(path, "current_now")? * read_micro(path, "voltage_now")?))
+        .filter(|w| *w > 0.0);
+    
+    let percentage = read_number::<f32>(path, "capacity")
+        .or_else(|| Some(energy_now? / energy_full? * 100.0))
+        .or_else(|| capacity_level_percent(&read_string(path, "capacity_level")?))?;
+    
+    let state = match read_string(path, "status").as_deref() {
+        Some("Charging") => BatteryState::Charging,
+        Some("Discharging") => BatteryState::Discharging,
+        Some("Full") => BatteryState::Full,
+        Some("Not charging") => BatteryState::NotCharging,
+        _ => BatteryState::Unknown,
+    };
+    
+    let health = match (energy_full, energy_full_design) {
+        (Some(full), Some(design)) if design > 0.0 => Some((full / design * 100.0).min(100.0)),
+        _ => None,
+    };
+    
+    let time_remaining = match (&state, energy_now, energy_full, power_watts) {
+        (BatteryState::Discharging, Some(now), _, Some(power)) => Some(now / power),
+        (BatteryState::Charging, Some(now), Some(full), Some(power)) => Some((full - now).max(0.0) / power),
+        _ => None,
+    }
+    .map(|hours| format_duration((hours * 3600.0) as u64));
+    
+    Some(Battery {
+        name,
+        manufacturer: read_string(path, "manufacturer"),
+        model: read_string(path, "model_name"),
+        technology: read_string(path, "technology").filter(|t| t != "Unknown"),
+        percentage,
+        state,
+        health,
+        cycle_count: read_number::<u32>(path, "cycle_count").filter(|c| *c > 0),
+        energy_full_wh: energy_full,
+        energy_full_design_wh: energy_full_design,
+        power_watts,
+        charge_start_threshold: read_number(path, "charge_control_start_threshold"),
+        charge_end_threshold: read_number(path, "charge_control_end_threshold"),
+        time_remaining,
     })
 }
 
+fn capacity_level_percent(level: &str) -> Option<f32> {
+    match level {
+        "Full" => Some(100.0),
+        "High" => Some(80.0),
+        "Normal" => Some(50.0),
+        "Low" => Some(20.0),
+        "Critical" => Some(5.0),
+        _ => None,
+    }
+}
+
+fn format_duration(secs: u64) -> String {
+    let hours = secs / 3600;
+    let mins = (secs % 3600) / 60;
+    
+    if hours > 0 {
+        format!("{}h {}m", hours, mins)
+    } else {
+        format!("{}m", mins)
+    }
+}
+
 impl BatteryInfo {
     pub fn display(&self) -> Option<String> {
         self.batteries.first().map(|b| b.display())
     }
+    
+    pub fn display_ac(&self) -> Option<String> {
+        self.ac_online.map(|online| {
+            if online { "connected".to_string() } else { "disconnected".to_string() }
+        })
+    }
+    
+    pub fn fields(&self) -> Fields {
+        let mut fields = self.batteries.first().map(|b| b.fields()).unwrap_or_default();
+        fields.push(("ac_online", self.ac_online.into()));
+        fields.push(("batteries", self.batteries.iter().map(|b| b.fields()).collect::<Vec<_>>().into()));
+        fields.push(("peripherals", self.peripherals.iter().map(|b| b.fields()).collect::<Vec<_>>().into()));
+        fields
+    }
 }
 
 impl Battery {
+    pub fn label(&self) -> String {
+        match (&self.manufacturer, &self.model) {
+            (Some(vendor), Some(model)) if !model.starts_with(vendor.as_str()) => format!("{} {}", vendor, model),
+            (_, Some(model)) => model.clone(),
+            _ => self.name.clone(),
+        }
+    }
+    
+    pub fn is_charging(&self) -> bool {
+        self.state == BatteryState::Charging
+    }
+    
     pub fn display(&self) -> String {
         let state_str = match self.state {
             BatteryState::Charging => "⚡ charging",
@@ -126,15 +254,67 @@ impl Battery {
             result.push_str(&format!(" - {}", time));
         }
         
+        if let Some(watts) = self.power_watts {
+            result.push_str(&format!(", {:.1} W", watts));
+        }
+        
         result
     }
     
+    pub fn display_details(&self) -> Option<String> {
+        let mut parts = Vec::new();
+        
+        if let Some(health) = self.health {
+            parts.push(format!("{:.0}%", health));
+        }
+        
+        if let (Some(full), Some(design)) = (self.energy_full_wh, self.energy_full_design_wh) {
+            parts.push(format!("{:.1} / {:.1} Wh", full, design));
+        }
+        
+        if let Some(cycles) = self.cycle_count {
+            parts.push(format!("{} cycles", cycles));
+        }
+        
+        match (self.charge_start_threshold, self.charge_end_threshold) {
+            (Some(start), Some(end)) if end < 100 => parts.push(format!("charge limit {}-{}%", start, end)),
+            (None, Some(end)) if end < 100 => parts.push(format!("charge limit {}%", end)),
+            _ => {}
+        }
+        
+        if parts.is_empty() {
+            None
+        } else {
+            Some(parts.join(", "))
+        }
+    }
+    
     pub fn get_bar(&self, width: usize) -> String {
-        let filled = (self.percentage / 100.0 * width as f32) as usize;
+        let filled = ((self.percentage / 100.0 * width as f32) as usize).min(width);
         let empty = width.saturating_sub(filled);
         
         format!("[{}{}]", "█".repeat(filled), "░".repeat(empty))
     }
+    
+    pub fn fields(&self) -> Fields {
+        vec![
+            ("name", self.name.as_str().into()),
+            ("label", self.label().into()),
+            ("manufacturer", self.manufacturer.clone().into()),
+            ("model", self.model.clone().into()),
+            ("technology", self.technology.clone().into()),
+            ("percent", self.percentage.into()),
+            ("state", self.state.to_string().into()),
+            ("health", self.health.into()),
+            ("cycles", self.cycle_count.into()),
+            ("energy_full_wh", self.energy_full_wh.into()),
+            ("energy_design_wh", self.energy_full_design_wh.into()),
+            ("power_watts", self.power_watts.into()),
+            ("charge_start", self.charge_start_threshold.into()),
+            ("charge_end", self.charge_end_threshold.into()),
+            ("time_remaining", self.time_remaining.clone().into()),
+        ]
+    }
 }
 
 impl std::fmt::Display for BatteryState {
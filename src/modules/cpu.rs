@@ -17,6 +17,7 @@
 
 use sysinfo::System;
 use std::fs;
+use super::Fields;
 
 #[derive(Debug, Clone)]
 pub struct CpuInfo {
@@ -96,4 +97,17 @@ impl CpuInfo {
             None => self.display(),
         }
     }
+    
+    pub fn fields(&self) -> Fields {
+        vec![
+            ("model", self.model.as_str().into()),
+            ("vendor", self.vendor.as_str().into()),
+            ("cores", self.cores.into()),
+            ("threads", self.threads.into()),
+            ("freq_mhz", self.frequency_mhz.into()),
+            ("freq_ghz", (self.frequency_mhz as f64 / 1000.0).into()),
+            ("usage", self.usage_percent.into()),
+            ("temp", self.temperature.into()),
+        ]
+    }
 }
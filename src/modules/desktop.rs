@@ -15,35 +15,174 @@
 * along with this program. If not, see <https://www.gnu.org/licenses/>
 */
 
+use std::collections::{HashMap, HashSet};
 use std::env;
-use std::process::Command;
+use std::fs;
+use std::path::{Path, PathBuf};
+use crate::config::config_home;
+use super::Fields;
 
 #[derive(Debug, Clone)]
 pub struct DesktopInfo {
     pub desktop_env: String,
+    pub de_version: Option<String>,
     pub display_server: String,
     pub wm: Option<String>,
+    pub wm_version: Option<String>,
     pub theme: Option<String>,
+    pub qt_theme: Option<String>,
     pub icons: Option<String>,
+    pub cursor: Option<String>,
+    pub cursor_size: Option<u32>,
+    pub font: Option<String>,
+    pub mono_font: Option<String>,
 }
 
+const KNOWN_WMS: &[(&str, &str)] = &[
+    ("Hyprland", "Hyprland"),
+    ("sway", "Sway"),
+    ("kwin_wayland", "KWin"),
+    ("kwin_x11", "KWin"),
+    ("kwin", "KWin"),
+    ("gnome-shell", "Mutter"),
+    ("mutter", "Mutter"),
+    ("river", "river"),
+    ("niri", "niri"),
+    ("wayfire", "Wayfire"),
+    ("labwc", "labwc"),
+    ("cosmic-comp", "cosmic-comp"),
+    ("dwl", "dwl"),
+    ("hikari", "hikari"),
+    ("weston", "Weston"),
+    ("gamescope", "gamescope"),
+    ("i3", "i3"),
+    ("bspwm", "bspwm"),
+    ("awesome", "Awesome"),
+    ("dwm", "dwm"),
+    ("xfwm4", "Xfwm4"),
+    ("openbox", "Openbox"),
+    ("marco", "Marco"),
+    ("muffin", "Muffin"),
+    ("cinnamon", "Muffin"),
+    ("budgie-wm", "Budgie WM"),
+    ("enlightenment", "Enlightenment"),
+    ("herbstluftwm", "herbstluftwm"),
+    ("qtile", "Qtile"),
+    ("xmonad", "xmonad"),
+    ("spectrwm", "spectrwm"),
+    ("leftwm", "LeftWM"),
+    ("fluxbox", "Fluxbox"),
+    ("icewm", "IceWM"),
+    ("jwm", "JWM"),
+    ("fvwm", "FVWM"),
+    ("fvwm3", "FVWM"),
+    ("wmaker", "Window Maker"),
+    ("twm", "twm"),
+];
+
+const WAYLAND_ONLY_WMS: &[&str] = &[
+    "Hyprland", "Sway", "river", "niri", "Wayfire", "labwc",
+    "cosmic-comp", "dwl", "hikari", "Weston",
+];
+
+const KNOWN_DES: &[(&str, &str)] = &[
+    ("plasmashell", "KDE Plasma"),
+    ("gnome-shell", "GNOME"),
+    ("xfce4-session", "Xfce"),
+    ("mate-session", "MATE"),
+    ("cinnamon-sessio", "Cinnamon"),
+    ("lxqt-session", "LXQt"),
+    ("lxsession", "LXDE"),
+    ("budgie-panel", "Budgie"),
+    ("gala", "Pantheon"),
+    ("dde-session", "Deepin"),
+    ("cosmic-session", "COSMIC"),
+];
+
 pub fn get_info() -> DesktopInfo {
-    let desktop_env = detect_desktop_env();
-    let display_server = detect_display_server();
-    let wm = detect_wm();
-    let theme = detect_theme(&desktop_env);
-    let icons = detect_icon_theme(&desktop_env);
+    let processes = running_process_names();
+    let session = read_session_properties();
+    
+    let desktop_env = detect_desktop_env(&processes, &session);
+    let de_version = detect_de_version(&desktop_env);
+    let wm = detect_wm(&processes);
+    let wm_version = wm.as_deref().and_then(|w| detect_wm_version(w, de_version.as_deref()));
+    let display_server = detect_display_server(&session, wm.as_deref());
+    let appearance = detect_appearance(&desktop_env);
     
     DesktopInfo {
         desktop_env,
+        de_version,
         display_server,
         wm,
-        theme,
-        icons,
+        wm_version,
+        theme: appearance.theme,
+        qt_theme: appearance.qt_theme,
+        icons: appearance.icons,
+        cursor: appearance.cursor,
+        cursor_size: appearance.cursor_size,
+        font: appearance.font,
+        mono_font: appearance.mono_font,
+    }
+}
+
+fn audit_session_id(pid: &str) -> Option<String> {
+    fs::read_to_string(format!("/proc/{}/sessionid", pid))
+        .ok()
+        .map(|s| s.trim().to_string())
+        .filter(|s| s != "4294967295")
+}
+
+fn running_process_names() -> HashSet<String> {
+    let mut names = HashSet::new();
+    let uid = users::get_current_uid();
+    let session = audit_session_id("self");
+    
+    if let Ok(entries) = fs::read_dir("/proc") {
+        for entry in entries.flatten() {
+            let Ok(pid) = entry.file_name().to_string_lossy().parse::<u32>() else {
+                continue;
+            };
+            
+            if super::shell::get_process_uid(pid) != Some(uid) {
+                continue;
+            }
+            if session.is_some() && audit_session_id(&pid.to_string()) != session {
+                continue;
+            }
+            
+            if let Ok(comm) = fs::read_to_string(entry.path().join("comm")) {
+                names.insert(comm.trim().to_string());
+            }
+        }
+    }
+    
+    names
+}
+
+fn read_session_properties() -> HashMap<String, String> {
+    let mut map = HashMap::new();
+    
+    let session_id = env::var("XDG_SESSION_ID").ok().or_else(|| {
+        audit_session_id("self")
+    });
+    
+    let Some(session_id) = session_id else {
+        return map;
+    };
+    
+    if let Ok(content) = fs::read_to_string(format!("/run/systemd/sessions/{}", session_id)) {
+        for line in content.lines() {
+            if let Some((key, value)) = line.split_once('=') {
+                map.insert(key.to_string(), value.to_string());
+            }
+        }
     }
+    
+    map
 }
 
-fn detect_desktop_env() -> String {
+fn detect_desktop_env(processes: &HashSet<String>, session: &HashMap<String, String>) -> String {
     
     if let Ok(de) = env::var("XDG_CURRENT_DESKTOP") {
         return normalize_de_name(&de);
@@ -53,6 +192,10 @@ fn detect_desktop_env() -> String {
         return normalize_de_name(&de);
     }
     
+    if let Some(de) = session.get("DESKTOP").filter(|d| !d.is_empty()) {
+        return normalize_de_name(de);
+    }
+    
     let checks = [
         ("KDE_FULL_SESSION", "KDE Plasma"),
         ("GNOME_DESKTOP_SESSION_ID", "GNOME"),
@@ -69,7 +212,13 @@ fn detect_desktop_env() -> String {
         }
     }
     
-    if let Some(wm) = detect_wm() {
+    for (process, name) in KNOWN_DES {
+        if processes.contains(*process) {
+            return name.to_string();
+        }
+    }
+    
+    if let Some(wm) = detect_wm(processes) {
         return wm;
     }
     
@@ -78,8 +227,8 @@ fn detect_desktop_env() -> String {
 
 fn normalize_de_name(de: &str) -> String {
     match de.to_lowercase().as_str() {
-        "kde" | "plasma" | "kde-plasma" => "KDE Plasma".to_string(),
-        "gnome" | "gnome-shell" | "ubuntu:gnome" => "GNOME".to_string(),
+        "kde" | "plasma" | "kde-plasma" | "plasmawayland" | "plasmax11" => "KDE Plasma".to_string(),
+        "gnome" | "gnome-shell" | "ubuntu:gnome" | "gnome-xorg" | "gnome-wayland" => "GNOME".to_string(),
         "xfce" | "xfce4" => "Xfce".to_string(),
         "mate" => "MATE".to_string(),
         "cinnamon" | "x-cinnamon" => "Cinnamon".to_string(),
@@ -98,37 +247,101 @@ fn normalize_de_name(de: &str) -> String {
         "awesome" => "Awesome".to_string(),
         "openbox" => "Openbox".to_string(),
         "cosmic" => "COSMIC".to_string(),
-        _ => de.to_string(),
+        "river" => "river".to_string(),
+        "niri" => "niri".to_string(),
+        "wayfire" => "Wayfire".to_string(),
+        "labwc" => "labwc".to_string(),
+        _ => match de.split_once(':') {
+            Some((first, _)) => normalize_de_name(first),
+            None => de.to_string(),
+        },
+    }
+}
+
+fn detect_de_version(de: &str) -> Option<String> {
+    match de {
+        "KDE Plasma" => {
+            let session_files = [
+                "/usr/share/wayland-sessions/plasma.desktop",
+                "/usr/share/xsessions/plasmax11.desktop",
+                "/usr/share/xsessions/plasma.desktop",
+            ];
+            session_files.iter()
+                .find_map(|path| read_desktop_entry_key(path, "X-KDE-PluginInfo-Version"))
+        }
+        "GNOME" => {
+            let content = fs::read_to_string("/usr/share/gnome/gnome-version.xml").ok()?;
+            let platform = extract_xml_tag(&content, "platform")?;
+            let minor = extract_xml_tag(&content, "minor")?;
+            match extract_xml_tag(&content, "micro") {
+                Some(micro) if !micro.is_empty() => Some(format!("{}.{}.{}", platform, minor, micro)),
+                _ => Some(format!("{}.{}", platform, minor)),
+            }
+        }
+        "Cinnamon" => read_desktop_entry_key("/usr/share/applications/cinnamon-settings.desktop", "X-Cinnamon-Version"),
+        _ => None,
     }
 }
 
-fn detect_display_server() -> String {
+fn read_desktop_entry_key(path: &str, key: &str) -> Option<String> {
+    let content = fs::read_to_string(path).ok()?;
+    content.lines()
+        .find_map(|line| line.strip_prefix(key)?.strip_prefix('='))
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+}
+
+fn extract_xml_tag(content: &str, tag: &str) -> Option<String> {
+    let open = format!("<{}>", tag);
+    let close = format!("</{}>", tag);
+    let start = content.find(&open)? + open.len();
+    let end = content[start..].find(&close)? + start;
+    Some(content[start..end].trim().to_string())
+}
+
+fn detect_display_server(session: &HashMap<String, String>, wm: Option<&str>) -> String {
+    
+    let session_type = env::var("XDG_SESSION_TYPE").ok()
+        .or_else(|| session.get("TYPE").cloned())
+        .unwrap_or_default();
+    
+    match session_type.to_lowercase().as_str() {
+        "wayland" => return "Wayland".to_string(),
+        "x11" => return "X11".to_string(),
+        "mir" => return "Mir".to_string(),
+        _ => {}
+    }
+    
     if env::var("WAYLAND_DISPLAY").is_ok() {
         "Wayland".to_string()
     } else if env::var("DISPLAY").is_ok() {
         "X11".to_string()
+    } else if wm.is_some_and(|w| WAYLAND_ONLY_WMS.contains(&w)) {
+        "Wayland".to_string()
+    } else if wm.is_some() {
+        "X11".to_string()
     } else {
         "TTY".to_string()
     }
 }
 
-fn detect_wm() -> Option<String> {
+fn detect_wm(processes: &HashSet<String>) -> Option<String> {
     
-    if let Ok(output) = Command::new("wmctrl").arg("-m").output() {
-        if output.status.success() {
-            let stdout = String::from_utf8_lossy(&output.stdout);
-            for line in stdout.lines() {
-                if line.starts_with("Name:") {
-                    return Some(line.replace("Name:", "").trim().to_string());
-                }
-            }
+    for (process, name) in KNOWN_WMS {
+        if processes.contains(*process) {
+            return Some(name.to_string());
         }
     }
     
+    if processes.iter().any(|p| p.starts_with("xmonad")) {
+        return Some("xmonad".to_string());
+    }
+    
     let wm_checks = [
         ("HYPRLAND_INSTANCE_SIGNATURE", "Hyprland"),
         ("SWAYSOCK", "Sway"),
         ("I3SOCK", "i3"),
+        ("NIRI_SOCKET", "niri"),
     ];
     
     for (var, name) in &wm_checks {
@@ -140,77 +353,323 @@ fn detect_wm() -> Option<String> {
     None
 }
 
-fn detect_theme(de: &str) -> Option<String> {
-    match de {
-        "GNOME" | "Budgie" | "Pantheon" | "Unity" => {
-            get_gsettings_value("org.gnome.desktop.interface", "gtk-theme")
-        }
-        "KDE Plasma" => {
-            
-            None 
-        }
-        "Xfce" => {
-            get_xfconf_value("xsettings", "/Net/ThemeName")
+fn detect_wm_version(wm: &str, de_version: Option<&str>) -> Option<String> {
+    match wm {
+        "KWin" | "Mutter" => de_version.map(|v| v.to_string()),
+        "Hyprland" => {
+            let signature = env::var("HYPRLAND_INSTANCE_SIGNATURE").ok()?;
+            signature.split('_').next()
+                .filter(|hash| hash.len() >= 7)
+                .map(|hash| format!("git {}", &hash[..7]))
         }
         _ => None,
     }
 }
 
-fn detect_icon_theme(de: &str) -> Option<String> {
-    match de {
-        "GNOME" | "Budgie" | "Pantheon" | "Unity" => {
-            get_gsettings_value("org.gnome.desktop.interface", "icon-theme")
+struct AppearanceSources {
+    gtk: HashMap<String, String>,
+    kdeglobals: IniFile,
+    kcminputrc: IniFile,
+    qtct: IniFile,
+    xfce: HashMap<String, String>,
+    xresources: HashMap<String, String>,
+    default_cursor: IniFile,
+}
+
+struct Appearance {
+    theme: Option<String>,
+    qt_theme: Option<String>,
+    icons: Option<String>,
+    cursor: Option<String>,
+    cursor_size: Option<u32>,
+    font: Option<String>,
+    mono_font: Option<String>,
+}
+
+pub type IniFile = crate::config::Table;
+
+fn read_ini(path: &Path) -> IniFile {
+    fs::read_to_string(path)
+        .map(|content| crate::config::parse_ini(&content))
+        .unwrap_or_default()
+}
+
+fn ini_value(ini: &IniFile, section: &str, key: &str) -> Option<String> {
+    ini.get(section)?
+        .get(key)
+        .filter(|v| !v.is_empty())
+        .cloned()
+}
+
+impl AppearanceSources {
+    fn load() -> Self {
+        let config = config_home();
+        let home = PathBuf::from(env::var("HOME").unwrap_or_default());
+        
+        let mut gtk = HashMap::new();
+        let gtk_files = [
+            home.join(".gtkrc-2.0"),
+            PathBuf::from("/etc/gtk-3.0/settings.ini"),
+            config.join("gtk-3.0/settings.ini"),
+            config.join("gtk-4.0/settings.ini"),
+        ];
+        for path in &gtk_files {
+            for values in read_ini(path).into_values() {
+                gtk.extend(values.into_iter().filter(|(_, v)| !v.is_empty()));
+            }
         }
-        "Xfce" => {
-            get_xfconf_value("xsettings", "/Net/IconThemeName")
+        
+        let qtct = [config.join("qt6ct/qt6ct.conf"), config.join("qt5ct/qt5ct.conf")]
+            .iter()
+            .map(|path| read_ini(path))
+            .find(|ini| !ini.is_empty())
+            .unwrap_or_default();
+        
+        AppearanceSources {
+            gtk,
+            kdeglobals: read_ini(&config.join("kdeglobals")),
+            kcminputrc: read_ini(&config.join("kcminputrc")),
+            qtct,
+            xfce: read_xfconf_channel(&config.join("xfce4/xfconf/xfce-perchannel-xml/xsettings.xml")),
+            xresources: read_xresources(&home.join(".Xresources"))
+                .or_else(|| read_xresources(&home.join(".Xdefaults")))
+                .unwrap_or_default(),
+            default_cursor: read_ini(&home.join(".icons/default/index.theme")),
         }
-        _ => None,
+    }
+    
+    fn gtk_value(&self, key: &str) -> Option<String> {
+        self.gtk.get(key).cloned()
+    }
+    
+    fn xfce_value(&self, key: &str) -> Option<String> {
+        self.xfce.get(key).filter(|v| !v.is_empty()).cloned()
     }
 }
 
-fn get_gsettings_value(schema: &str, key: &str) -> Option<String> {
-    let output = Command::new("gsettings")
-        .args(["get", schema, key])
-        .output()
-        .ok()?;
+fn read_xfconf_channel(path: &Path) -> HashMap<String, String> {
+    let mut map = HashMap::new();
+    let Ok(content) = fs::read_to_string(path) else {
+        return map;
+    };
     
-    if output.status.success() {
-        let value = String::from_utf8_lossy(&output.stdout)
-            .trim()
-            .trim_matches('\'')
-            .to_string();
-        if !value.is_empty() {
-            return Some(value);
+    let mut stack: Vec<String> = Vec::new();
+    for line in content.lines() {
+        let line = line.trim();
+        if line.starts_with("</property") {
+            stack.pop();
+            continue;
+        }
+        if !line.starts_with("<property") {
+            continue;
+        }
+        
+        let Some(name) = extract_xml_attr(line, "name") else {
+            continue;
+        };
+        
+        if line.ends_with("/>") {
+            let key = stack.iter().chain(std::iter::once(&name)).cloned().collect::<Vec<_>>().join("/");
+            if let Some(value) = extract_xml_attr(line, "value") {
+                map.insert(key, value);
+            }
+        } else {
+            stack.push(name);
         }
     }
-    None
+    
+    map
+}
+
+fn extract_xml_attr(tag: &str, attr: &str) -> Option<String> {
+    let needle = format!("{}=\"", attr);
+    let start = tag.find(&needle)? + needle.len();
+    let end = tag[start..].find('"')? + start;
+    Some(tag[start..end].to_string())
 }
 
-fn get_xfconf_value(channel: &str, property: &str) -> Option<String> {
-    let output = Command::new("xfconf-query")
-        .args(["-c", channel, "-p", property])
-        .output()
-        .ok()?;
+fn read_xresources(path: &Path) -> Option<HashMap<String, String>> {
+    let content = fs::read_to_string(path).ok()?;
+    let mut map = HashMap::new();
     
-    if output.status.success() {
-        let value = String::from_utf8_lossy(&output.stdout).trim().to_string();
-        if !value.is_empty() {
-            return Some(value);
+    for line in content.lines() {
+        let line = line.trim();
+        if line.starts_with('!') || line.starts_with('#') {
+            continue;
+        }
+        if let Some((key, value)) = line.split_once(':') {
+            map.insert(key.trim().to_string(), value.trim().to_string());
         }
     }
-    None
+    
+    Some(map)
+}
+
+fn detect_appearance(de: &str) -> Appearance {
+    let sources = AppearanceSources::load();
+    let is_kde = de == "KDE Plasma";
+    let is_xfce = de == "Xfce";
+    
+    let gtk_theme = is_xfce.then(|| sources.xfce_value("Net/ThemeName")).flatten()
+        .or_else(|| sources.gtk_value("gtk-theme-name"));
+    
+    let kde_style = ini_value(&sources.kdeglobals, "KDE", "widgetStyle")
+        .or_else(|| ini_value(&sources.kdeglobals, "General", "widgetStyle"));
+    let kde_theme = match (kde_style, ini_value(&sources.kdeglobals, "General", "ColorScheme")) {
+        (Some(style), Some(scheme)) => Some(format!("{} ({})", style, scheme)),
+        (style, scheme) => style.or(scheme),
+    };
+    let qt_theme = if is_kde {
+        kde_theme.or_else(|| ini_value(&sources.qtct, "Appearance", "style"))
+    } else {
+        ini_value(&sources.qtct, "Appearance", "style").or(kde_theme)
+    };
+    
+    let kde_icons = ini_value(&sources.kdeglobals, "Icons", "Theme");
+    let gtk_icons = is_xfce.then(|| sources.xfce_value("Net/IconThemeName")).flatten()
+        .or_else(|| sources.gtk_value("gtk-icon-theme-name"));
+    let qtct_icons = ini_value(&sources.qtct, "Appearance", "icon_theme");
+    let icons = if is_kde {
+        kde_icons.or(qtct_icons).or(gtk_icons)
+    } else {
+        gtk_icons.or(qtct_icons).or(kde_icons)
+    };
+    
+    let kde_cursor = ini_value(&sources.kcminputrc, "Mouse", "cursorTheme");
+    let gtk_cursor = is_xfce.then(|| sources.xfce_value("Gtk/CursorThemeName")).flatten()
+        .or_else(|| sources.gtk_value("gtk-cursor-theme-name"));
+    let fallback_cursor = sources.xresources.get("Xcursor.theme").cloned()
+        .or_else(|| ini_value(&sources.default_cursor, "Icon Theme", "Inherits"));
+    let cursor = if is_kde {
+        kde_cursor.or(gtk_cursor)
+    } else {
+        gtk_cursor.or(kde_cursor)
+    }.or(fallback_cursor);
+    
+    let kde_cursor_size = ini_value(&sources.kcminputrc, "Mouse", "cursorSize");
+    let gtk_cursor_size = is_xfce.then(|| sources.xfce_value("Gtk/CursorThemeSize")).flatten()
+        .or_else(|| sources.gtk_value("gtk-cursor-theme-size"));
+    let cursor_size = if is_kde {
+        kde_cursor_size.or(gtk_cursor_size)
+    } else {
+        gtk_cursor_size.or(kde_cursor_size)
+    }
+    .or_else(|| sources.xresources.get("Xcursor.size").cloned())
+    .and_then(|size| size.parse().ok())
+    .filter(|size| *size > 0);
+    
+    let kde_font = ini_value(&sources.kdeglobals, "General", "font");
+    let gtk_font = is_xfce.then(|| sources.xfce_value("Gtk/FontName")).flatten()
+        .or_else(|| sources.gtk_value("gtk-font-name"));
+    let qtct_font = ini_value(&sources.qtct, "Fonts", "general");
+    let font = if is_kde {
+        kde_font.or(qtct_font).or(gtk_font)
+    } else {
+        gtk_font.or(qtct_font).or(kde_font)
+    }.and_then(|f| format_font(&f));
+    
+    let kde_mono = ini_value(&sources.kdeglobals, "General", "fixed");
+    let xfce_mono = is_xfce.then(|| sources.xfce_value("Gtk/MonospaceFontName")).flatten();
+    let qtct_mono = ini_value(&sources.qtct, "Fonts", "fixed");
+    let mono_font = if is_kde {
+        kde_mono.or(qtct_mono).or(xfce_mono)
+    } else {
+        xfce_mono.or(qtct_mono).or(kde_mono)
+    }.and_then(|f| format_font(&f));
+    
+    Appearance {
+        theme: gtk_theme,
+        qt_theme,
+        icons,
+        cursor,
+        cursor_size,
+        font,
+        mono_font,
+    }
+}
+
+fn format_font(raw: &str) -> Option<String> {
+    let raw = raw.trim().trim_matches('"');
+    if raw.is_empty() || raw.starts_with("@Variant") {
+        return None;
+    }
+    
+    if raw.contains(',') {
+        let mut parts = raw.split(',');
+        let name = parts.next()?.trim();
+        return match parts.next().and_then(|size| size.trim().parse::<f32>().ok()) {
+            Some(size) if size > 0.0 => Some(format!("{} ({}pt)", name, size)),
+            _ => Some(name.to_string()),
+        };
+    }
+    
+    match raw.rsplit_once(' ') {
+        Some((name, size)) if size.parse::<f32>().is_ok() => Some(format!("{} ({}pt)", name, size)),
+        _ => Some(raw.to_string()),
+    }
 }
 
 impl DesktopInfo {
     pub fn display(&self) -> String {
-        format!("{} ({})", self.desktop_env, self.display_server)
+        match &self.de_version {
+            Some(v) => format!("{} {} ({})", self.desktop_env, v, self.display_server),
+            None => format!("{} ({})", self.desktop_env, self.display_server),
+        }
     }
     
     pub fn display_wm(&self) -> String {
-        self.wm.clone().unwrap_or_else(|| self.desktop_env.clone())
+        let wm = self.wm.clone().unwrap_or_else(|| self.desktop_env.clone());
+        match &self.wm_version {
+            Some(v) => format!("{} {}", wm, v),
+            None => wm,
+        }
+    }
+    
+    pub fn has_separate_wm(&self) -> bool {
+        self.wm.as_deref().is_some_and(|wm| wm != self.desktop_env)
     }
     
     pub fn display_theme(&self) -> Option<String> {
-        self.theme.clone()
+        match (&self.theme, &self.qt_theme) {
+            (Some(gtk), Some(qt)) if gtk.eq_ignore_ascii_case(qt) => Some(format!("{} [GTK/Qt]", gtk)),
+            (Some(gtk), Some(qt)) => Some(format!("{} [GTK], {} [Qt]", gtk, qt)),
+            (Some(gtk), None) => Some(format!("{} [GTK]", gtk)),
+            (None, Some(qt)) => Some(format!("{} [Qt]", qt)),
+            (None, None) => None,
+        }
+    }
+    
+    pub fn display_cursor(&self) -> Option<String> {
+        let cursor = self.cursor.clone()?;
+        match self.cursor_size {
+            Some(size) => Some(format!("{} ({}px)", cursor, size)),
+            None => Some(cursor),
+        }
+    }
+    
+    pub fn display_font(&self) -> Option<String> {
+        match (&self.font, &self.mono_font) {
+            (Some(font), Some(mono)) => Some(format!("{}, {} [mono]", font, mono)),
+            (Some(font), None) => Some(font.clone()),
+            (None, Some(mono)) => Some(format!("{} [mono]", mono)),
+            (None, None) => None,
+        }
+    }
+    
+    pub fn fields(&self) -> Fields {
+        vec![
+            ("de", self.desktop_env.as_str().into()),
+            ("de_version", self.de_version.clone().into()),
+            ("display_server", self.display_server.as_str().into()),
+            ("wm", self.wm.clone().into()),
+            ("wm_version", self.wm_version.clone().into()),
+            ("theme", self.theme.clone().into()),
+            ("qt_theme", self.qt_theme.clone().into()),
+            ("icons", self.icons.clone().into()),
+            ("cursor", self.cursor.clone().into()),
+            ("cursor_size", self.cursor_size.into()),
+            ("font", self.font.clone().into()),
+            ("mono_font", self.mono_font.clone().into()),
+        ]
     }
 }
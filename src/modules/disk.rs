@@ -17,6 +17,7 @@
 
 use sysinfo::{System, Disks};
 use super::memory::format_bytes;
+use super::Fields;
 
 #[derive(Debug, Clone)]
 pub struct DiskInfo {
@@ -118,6 +119,18 @@ impl DiskInfo {
     pub fn display_all(&self) -> Vec<String> {
         self.disks.iter().map(|d| d.display()).collect()
     }
+    
+    pub fn fields(&self) -> Fields {
+        let root = self.disks.iter()
+            .find(|d| d.mount_point == "/")
+            .or_else(|| self.disks.first());
+        let mut fields = root.map(|disk| disk.fields()).unwrap_or_default();
+        fields.push(("all_used_bytes", self.used_bytes.into()));
+        fields.push(("all_total_bytes", self.total_bytes.into()));
+        fields.push(("all_percent", self.usage_percent.into()));
+        fields.push(("disks", self.disks.iter().map(|disk| disk.fields()).collect::<Vec<_>>().into()));
+        fields
+    }
 }
 
 impl DiskDevice {
@@ -133,4 +146,19 @@ impl DiskDevice {
     pub fn display_with_mount(&self) -> String {
         format!("{}: {}", self.mount_point, self.display())
     }
+    
+    pub fn fields(&self) -> Fields {
+        vec![
+            ("name", self.name.as_str().into()),
+            ("mount", self.mount_point.as_str().into()),
+            ("fs", self.fs_type.as_str().into()),
+            ("used", format_bytes(self.used_bytes).into()),
+            ("total", format_bytes(self.total_bytes).into()),
+            ("available", format_bytes(self.available_bytes).into()),
+            ("percent", self.usage_percent.into()),
+            ("used_bytes", self.used_bytes.into()),
+            ("total_bytes", self.total_bytes.into()),
+            ("available_bytes", self.available_bytes.into()),
+        ]
+    }
 }
@@ -17,6 +17,7 @@
 
 use std::process::Command;
 use std::fs;
+use super::Fields;
 
 #[derive(Debug, Clone)]
 pub struct DisplayInfo {
@@ -249,6 +250,13 @@ impl DisplayInfo {
         self.displays.iter().find(|d| d.primary)
             .or_else(|| self.displays.first())
     }
+    
+    pub fn fields(&self) -> Fields {
+        let mut fields = self.primary().map(|d| d.fields()).unwrap_or_default();
+        fields.push(("count", self.displays.len().into()));
+        fields.push(("displays", self.displays.iter().map(|d| d.fields()).collect::<Vec<_>>().into()));
+        fields
+    }
 }
 
 impl Display {
@@ -259,4 +267,15 @@ impl Display {
             format!("{}x{}", self.width, self.height)
         }
     }
+    
+    pub fn fields(&self) -> Fields {
+        vec![
+            ("name", self.name.as_str().into()),
+            ("width", self.width.into()),
+            ("height", self.height.into()),
+            ("resolution", format!("{}x{}", self.width, self.height).into()),
+            ("refresh", self.refresh_rate.into()),
+            ("primary", self.primary.into()),
+        ]
+    }
 }
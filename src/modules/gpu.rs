@@ -17,6 +17,7 @@
 
 use std::process::Command;
 use std::fs;
+use super::Fields;
 
 #[derive(Debug, Clone)]
 pub struct GpuInfo {
@@ -178,6 +179,13 @@ impl GpuInfo {
             .collect::<Vec<_>>()
             .join(", ")
     }
+    
+    pub fn fields(&self) -> Fields {
+        let mut fields = self.gpus.first().map(|gpu| gpu.fields()).unwrap_or_default();
+        fields.push(("count", self.gpus.len().into()));
+        fields.push(("gpus", self.gpus.iter().map(|gpu| gpu.fields()).collect::<Vec<_>>().into()));
+        fields
+    }
 }
 
 impl GpuDevice {
@@ -188,4 +196,13 @@ impl GpuDevice {
             self.model.clone()
         }
     }
+    
+    pub fn fields(&self) -> Fields {
+        vec![
+            ("vendor", self.vendor.as_str().into()),
+            ("model", self.model.as_str().into()),
+            ("driver", self.driver.clone().into()),
+            ("vram_mb", self.vram_mb.into()),
+        ]
+    }
 }
@@ -0,0 +1,253 @@
+/*
+* Copyright (C) 2026 compiledkernel-idk <https://github.com/compiledkernel-idk>
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program. If not, see <https://www.gnu.org/licenses/>
+*/
+
+use std::fs;
+use std::path::Path;
+use super::Fields;
+
+const DMI_PATH: &str = "/sys/class/dmi/id";
+const SECURE_BOOT_VAR: &str = "/sys/firmware/efi/efivars/SecureBoot-8be4df61-93ca-11d2-aa0d-00e098032b8c";
+
+const JUNK_VALUES: &[&str] = &[
+    "to be filled by o.e.m.",
+    "to be filled by oem",
+    "default string",
+    "system product name",
+    "system manufacturer",
+    "system version",
+    "system serial number",
+    "base board product name",
+    "chassis manufacturer",
+    "not specified",
+    "not applicable",
+    "type1productconfigid",
+    "all series",
+    "o.e.m.",
+    "oem",
+    "none",
+    "n/a",
+    "invalid",
+    "unknown",
+    "0123456789",
+    "x.x",
+];
+
+#[derive(Debug, Clone)]
+pub struct HostInfo {
+    pub product_name: Option<String>,
+    pub product_version: Option<String>,
+    pub sys_vendor: Option<String>,
+    pub board_vendor: Option<String>,
+    pub board_name: Option<String>,
+    pub bios_vendor: Option<String>,
+    pub bios_version: Option<String>,
+    pub bios_date: Option<String>,
+    pub chassis_type: Option<String>,
+    pub firmware: FirmwareMode,
+    pub secure_boot: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FirmwareMode {
+    Uefi,
+    Legacy,
+}
+
+pub fn get_info() -> HostInfo {
+    let product_name = read_dmi("product_name").or_else(read_device_tree_model);
+    
+    HostInfo {
+        product_name,
+        product_version: read_dmi("product_version"),
+        sys_vendor: read_dmi("sys_vendor"),
+        board_vendor: read_dmi("board_vendor"),
+        board_name: read_dmi("board_name"),
+        bios_vendor: read_dmi("bios_vendor"),
+        bios_version: read_dmi("bios_version"),
+        bios_date: read_dmi("bios_date").map(|date| normalize_bios_date(&date)),
+        chassis_type: read_dmi("chassis_type").and_then(|code| chassis_name(&code)),
+        firmware: if Path::new("/sys/firmware/efi").exists() {
+            FirmwareMode::Uefi
+        } else {
+            FirmwareMode::Legacy
+        },
+        secure_boot: read_secure_boot(),
+    }
+}
+
+fn read_dmi(field: &str) -> Option<String> {
+    let value = fs::read_to_string(Path::new(DMI_PATH).join(field)).ok()?;
+    clean_value(&value)
+}
+
+fn read_device_tree_model() -> Option<String> {
+    let value = fs::read_to_string("/sys/firmware/devicetree/base/model").ok()?;
+    clean_value(value.trim_end_matches('\0'))
+}
+
+fn clean_value(raw: &str) -> Option<String> {
+    let value = raw.trim();
+    if value.is_empty() || is_junk(value) {
+        None
+    } else {
+        Some(value.to_string())
+    }
+}
+
+pub fn is_junk(value: &str) -> bool {
+    let lower = value.trim().to_lowercase();
+    JUNK_VALUES.contains(&lower.as_str())
+        || lower.chars().all(|c| c == '0' || c == ' ' || c == '.')
+}
+
+fn normalize_bios_date(date: &str) -> String {
+    let parts: Vec<&str> = date.split('/').collect();
+    if parts.len() == 3 && parts[2].len() == 4 {
+        format!("{}-{}-{}", parts[2], parts[0], parts[1])
+    } else {
+        date.to_string()
+    }
+}
+
+fn chassis_name(code: &str) -> Option<String> {
+    let name = match code.trim().parse::<u32>().ok()? {
+        3 => "Desktop",
+        4 => "Low Profile Desktop",
+        5 => "Pizza Box",
+        6 => "Mini Tower",
+        7 => "Tower",
+        8 => "Portable",
+        9 => "Laptop",
+        10 => "Notebook",
+        11 => "Hand Held",
+        12 => "Docking Station",
+        13 => "All in One",
+        14 => "Sub Notebook",
+        15 => "Space-saving",
+        16 => "Lunch Box",
+        17 => "Main Server Chassis",
+        23 => "Rack Mount Chassis",
+        24 => "Sealed-case PC",
+        30 => "Tablet",
+        31 => "Convertible",
+        32 => "Detachable",
+        33 => "IoT Gateway",
+        34 => "Embedded PC",
+        35 => "Mini PC",
+        36 => "Stick PC",
+        _ => return None,
+    };
+    Some(name.to_string())
+}
+
+fn read_secure_boot() -> Option<bool> {
+    let data = fs::read(SECURE_BOOT_VAR).ok()?;
+    data.get(4).map(|value| *value == 1)
+}
+
+impl HostInfo {
+    pub fn has_host(&self) -> bool {
+        self.product_name.is_some() || self.board_name.is_some()
+    }
+    
+    pub fn display(&self) -> String {
+        let vendor = self.sys_vendor.as_deref().unwrap_or_default();
+        
+        let model = match (&self.product_name, &self.product_version) {
+            (Some(name), Some(version)) if vendor.eq_ignore_ascii_case("lenovo") => {
+                format!("{} ({})", version, name)
+            }
+            (Some(name), Some(version)) if !name.contains(version.as_str()) => {
+                format!("{} {}", name, version)
+            }
+            (Some(name), _) => name.clone(),
+            (None, _) => self.display_board().unwrap_or_default(),
+        };
+        
+        let model = if !vendor.is_empty() && !model.to_lowercase().starts_with(&vendor.to_lowercase()) {
+            format!("{} {}", vendor, model)
+        } else {
+            model
+        };
+        
+        match &self.chassis_type {
+            Some(chassis) => format!("{} [{}]", model, chassis),
+            None => model,
+        }
+    }
+    
+    pub fn display_board(&self) -> Option<String> {
+        let name = self.board_name.as_ref()?;
+        if self.product_name.as_ref() == Some(name) {
+            return None;
+        }
+        match &self.board_vendor {
+            Some(vendor) if !name.starts_with(vendor.as_str()) => Some(format!("{} {}", vendor, name)),
+            _ => Some(name.clone()),
+        }
+    }
+    
+    pub fn display_bios(&self) -> Option<String> {
+        let mut result = match (&self.bios_vendor, &self.bios_version) {
+            (Some(vendor), Some(version)) => format!("{} {}", vendor, version),
+            (Some(vendor), None) => vendor.clone(),
+            (None, Some(version)) => version.clone(),
+            (None, None) => return None,
+        };
+        
+        if let Some(date) = &self.bios_date {
+            result.push_str(&format!(" ({})", date));
+        }
+        
+        let firmware = match self.firmware {
+            FirmwareMode::Uefi => "UEFI",
+            FirmwareMode::Legacy => "Legacy",
+        };
+        match self.secure_boot {
+            Some(true) => result.push_str(&format!(" [{}, Secure Boot on]", firmware)),
+            Some(false) => result.push_str(&format!(" [{}, Secure Boot off]", firmware)),
+            None => result.push_str(&format!(" [{}]", firmware)),
+        }
+        
+        Some(result)
+    }
+    
+    pub fn bios_year(&self) -> Option<u32> {
+        self.bios_date.as_ref()?.get(..4)?.parse().ok()
+    }
+    
+    pub fn fields(&self) -> Fields {
+        let firmware = match self.firmware {
+            FirmwareMode::Uefi => "UEFI",
+            FirmwareMode::Legacy => "Legacy",
+        };
+        vec![
+            ("model", Some(self.display()).filter(|_| self.has_host()).into()),
+            ("product", self.product_name.clone().into()),
+            ("version", self.product_version.clone().into()),
+            ("vendor", self.sys_vendor.clone().into()),
+            ("chassis", self.chassis_type.clone().into()),
+            ("board", self.board_name.clone().into()),
+            ("board_vendor", self.board_vendor.clone().into()),
+            ("bios_vendor", self.bios_vendor.clone().into()),
+            ("bios_version", self.bios_version.clone().into()),
+            ("bios_date", self.bios_date.clone().into()),
+            ("firmware", firmware.into()),
+            ("secure_boot", self.secure_boot.into()),
+        ]
+    }
+}
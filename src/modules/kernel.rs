@@ -16,6 +16,7 @@
 */
 
 use std::fs;
+use super::Fields;
 
 #[derive(Debug, Clone)]
 pub struct KernelInfo {
@@ -46,4 +47,12 @@ impl KernelInfo {
     pub fn display(&self) -> String {
         self.release.clone()
     }
+    
+    pub fn fields(&self) -> Fields {
+        vec![
+            ("release", self.release.as_str().into()),
+            ("version", self.version.as_str().into()),
+            ("arch", self.arch.as_str().into()),
+        ]
+    }
 }
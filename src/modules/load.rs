@@ -0,0 +1,205 @@
+/*
+* Copyright (C) 2026 compiledkernel-idk <https://github.com/compiledkernel-idk>
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program. If not, see <https://www.gnu.org/licenses/>
+*/
+
+use std::fs;
+use super::Fields;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Severity {
+    Good,
+    Warn,
+    Bad,
+}
+
+#[derive(Debug, Clone)]
+pub struct Pressure {
+    pub resource: String,
+    pub some_avg10: f32,
+    pub some_avg60: f32,
+    pub some_avg300: f32,
+    pub full_avg10: Option<f32>,
+}
+
+#[derive(Debug, Clone)]
+pub struct LoadInfo {
+    pub load_average: Option<(f32, f32, f32)>,
+    pub cpu_count: usize,
+    pub processes: usize,
+    pub running: Option<usize>,
+    pub threads: Option<usize>,
+    pub pressure: Vec<Pressure>,
+}
+
+const PRESSURE_RESOURCES: &[(&str, &str)] = &[
+    ("cpu", "CPU"),
+    ("memory", "Memory"),
+    ("io", "IO"),
+];
+
+pub fn get_info() -> LoadInfo {
+    let (running, threads) = read_scheduler_entities().unzip();
+    
+    LoadInfo {
+        load_average: read_load_average(),
+        cpu_count: online_cpu_count(),
+        processes: count_processes(),
+        running: read_procs_running().or(running),
+        threads,
+        pressure: PRESSURE_RESOURCES.iter()
+            .filter_map(|(file, name)| read_pressure(file, name))
+            .collect(),
+    }
+}
+
+fn read_load_average() -> Option<(f32, f32, f32)> {
+    let content = fs::read_to_string("/proc/loadavg").ok()?;
+    let mut parts = content.split_whitespace();
+    Some((
+        parts.next()?.parse().ok()?,
+        parts.next()?.parse().ok()?,
+        parts.next()?.parse().ok()?,
+    ))
+}
+
+fn online_cpu_count() -> usize {
+    let count = unsafe { libc::sysconf(libc::_SC_NPROCESSORS_ONLN) };
+    if count > 0 { count as usize } else { 1 }
+}
+
+fn count_processes() -> usize {
+    fs::read_dir("/proc")
+        .map(|entries| {
+            entries.flatten()
+                .filter(|entry| {
+                    entry.file_name().to_string_lossy().bytes().all(|b| b.is_ascii_digit())
+                })
+                .count()
+        })
+        .unwrap_or(0)
+}
+
+fn read_procs_running() -> Option<usize> {
+    let content = fs::read_to_string("/proc/stat").ok()?;
+    content.lines()
+        .find_map(|line| line.strip_prefix("procs_running "))
+        .and_then(|value| value.trim().parse().ok())
+}
+
+fn read_scheduler_entities() -> Option<(usize, usize)> {
+    let content = fs::read_to_string("/proc/loadavg").ok()?;
+    let (running, total) = content.split_whitespace().nth(3)?.split_once('/')?;
+    Some((running.parse().ok()?, total.parse().ok()?))
+}
+
+fn read_pressure(file: &str, name: &str) -> Option<Pressure> {
+    let content = fs::read_to_string(format!("/proc/pressure/{}", file)).ok()?;
+    let mut some = None;
+    let mut full = None;
+    
+    for line in content.lines() {
+        let mut fields = line.split_whitespace();
+        let kind = fields.next()?;
+        let averages: Vec<f32> = fields
+            .filter_map(|field| field.split_once('='))
+            .filter(|(key, _)| key.starts_with("avg"))
+            .filter_map(|(_, value)| value.parse().ok())
+            .collect();
+        
+        match kind {
+            "some" => some = Some(averages),
+            "full" => full = averages.first().copied(),
+            _ => {}
+        }
+    }
+    
+    let some = some?;
+    Some(Pressure {
+        resource: name.to_string(),
+        some_avg10: *some.first()?,
+        some_avg60: *some.get(1)?,
+        some_avg300: *some.get(2)?,
+        full_avg10: full,
+    })
+}
+
+impl Pressure {
+    pub fn severity(&self) -> Severity {
+        if self.some_avg10 < 10.0 {
+            Severity::Good
+        } else if self.some_avg10 < 30.0 {
+            Severity::Warn
+        } else {
+            Severity::Bad
+        }
+    }
+    
+    pub fn display(&self) -> String {
+        format!("{} {:.1}%", self.resource, self.some_avg10)
+    }
+    
+    pub fn fields(&self) -> Fields {
+        vec![
+            ("resource", self.resource.as_str().into()),
+            ("some_avg10", self.some_avg10.into()),
+            ("some_avg60", self.some_avg60.into()),
+            ("some_avg300", self.some_avg300.into()),
+            ("full_avg10", self.full_avg10.into()),
+        ]
+    }
+}
+
+impl LoadInfo {
+    pub fn load_severity(&self, load: f32) -> Severity {
+        let ratio = load / self.cpu_count.max(1) as f32;
+        if ratio < 0.7 {
+            Severity::Good
+        } else if ratio < 1.0 {
+            Severity::Warn
+        } else {
+            Severity::Bad
+        }
+    }
+    
+    pub fn load_values(&self) -> Option<[f32; 3]> {
+        self.load_average.map(|(one, five, fifteen)| [one, five, fifteen])
+    }
+    
+    pub fn display_processes(&self) -> String {
+        let mut result = format!("{}", self.processes);
+        if let Some(running) = self.running {
+            result.push_str(&format!(" ({} running)", running));
+        }
+        if let Some(threads) = self.threads {
+            result.push_str(&format!(", {} threads", threads));
+        }
+        result
+    }
+    
+    pub fn fields(&self) -> Fields {
+        let values = self.load_values();
+        vec![
+            ("load1", values.map(|v| v[0]).into()),
+            ("load5", values.map(|v| v[1]).into()),
+            ("load15", values.map(|v| v[2]).into()),
+            ("cpus", self.cpu_count.into()),
+            ("processes", self.processes.into()),
+            ("running", self.running.into()),
+            ("threads", self.threads.into()),
+            ("pressure", self.pressure.iter().map(|p| p.fields()).collect::<Vec<_>>().into()),
+        ]
+    }
+}
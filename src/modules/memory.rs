@@ -16,6 +16,7 @@
 */
 
 use sysinfo::System;
+use super::Fields;
 
 #[derive(Debug, Clone)]
 pub struct MemoryInfo {
@@ -73,6 +74,25 @@ impl MemoryInfo {
     pub fn display_with_percent(&self) -> String {
         format!("{} ({:.0}%)", self.display(), self.usage_percent)
     }
+    
+    pub fn fields(&self) -> Fields {
+        vec![
+            ("used", format_bytes(self.used_bytes).into()),
+            ("total", format_bytes(self.total_bytes).into()),
+            ("free", format_bytes(self.free_bytes).into()),
+            ("available", format_bytes(self.available_bytes).into()),
+            ("percent", self.usage_percent.into()),
+            ("used_bytes", self.used_bytes.into()),
+            ("total_bytes", self.total_bytes.into()),
+            ("free_bytes", self.free_bytes.into()),
+            ("available_bytes", self.available_bytes.into()),
+            ("swap_used", format_bytes(self.swap_used_bytes).into()),
+            ("swap_total", format_bytes(self.swap_total_bytes).into()),
+            ("swap_percent", self.swap_usage_percent.into()),
+            ("swap_used_bytes", self.swap_used_bytes.into()),
+            ("swap_total_bytes", self.swap_total_bytes.into()),
+        ]
+    }
 }
 
 pub fn format_bytes(bytes: u64) -> String {
@@ -16,12 +16,14 @@
 */
 
 pub mod os;
+pub mod host;
 pub mod kernel;
 pub mod cpu;
 pub mod gpu;
 pub mod memory;
 pub mod disk;
 pub mod uptime;
+pub mod load;
 pub mod shell;
 pub mod desktop;
 pub mod display;
@@ -29,45 +31,300 @@ pub mod battery;
 pub mod network;
 pub mod packages;
 pub mod user;
+pub mod virtualization;
 
+use std::fmt;
+use std::time::Duration;
 use sysinfo::System;
 
+pub const MODULES: &[&str] = &[
+    "user",
+    "os",
+    "host",
+    "kernel",
+    "virtualization",
+    "cpu",
+    "gpu",
+    "memory",
+    "disk",
+    "uptime",
+    "load",
+    "shell",
+    "terminal",
+    "desktop",
+    "display",
+    "battery",
+    "packages",
+    "network",
+];
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    Text(String),
+    List(Vec<Value>),
+    Map(Fields),
+}
+
+pub type Fields = Vec<(&'static str, Value)>;
+
+impl Value {
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Value::Null => true,
+            Value::Text(text) => text.is_empty(),
+            Value::List(items) => items.is_empty(),
+            Value::Map(fields) => fields.is_empty(),
+            _ => false,
+        }
+    }
+    
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        match self {
+            Value::Map(fields) => fields.iter().find(|(name, _)| *name == key).map(|(_, value)| value),
+            Value::List(items) => items.get(key.parse::<usize>().ok()?),
+            _ => None,
+        }
+    }
+    
+    pub fn format_precision(&self, precision: usize) -> String {
+        match self {
+            Value::Float(value) => format!("{:.*}", precision, value),
+            Value::Int(value) => format!("{:.*}", precision, *value as f64),
+            other => other.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Value::Null => Ok(()),
+            Value::Bool(value) => write!(f, "{}", value),
+            Value::Int(value) => write!(f, "{}", value),
+            Value::Float(value) => {
+                let text = format!("{:.2}", value);
+                write!(f, "{}", text.trim_end_matches('0').trim_end_matches('.'))
+            }
+            Value::Text(text) => write!(f, "{}", text),
+            Value::List(items) => {
+                let items: Vec<String> = items.iter().map(|item| item.to_string()).collect();
+                write!(f, "{}", items.join(", "))
+            }
+            Value::Map(fields) => {
+                let fields: Vec<String> = fields.iter()
+                    .filter(|(_, value)| !value.is_empty())
+                    .map(|(name, value)| format!("{}: {}", name, value))
+                    .collect();
+                write!(f, "{}", fields.join(", "))
+            }
+        }
+    }
+}
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        Value::Bool(value)
+    }
+}
+
+impl From<i64> for Value {
+    fn from(value: i64) -> Self {
+        Value::Int(value)
+    }
+}
+
+impl From<u64> for Value {
+    fn from(value: u64) -> Self {
+        Value::Int(value as i64)
+    }
+}
+
+impl From<u32> for Value {
+    fn from(value: u32) -> Self {
+        Value::Int(value as i64)
+    }
+}
+
+impl From<u8> for Value {
+    fn from(value: u8) -> Self {
+        Value::Int(value as i64)
+    }
+}
+
+impl From<usize> for Value {
+    fn from(value: usize) -> Self {
+        Value::Int(value as i64)
+    }
+}
+
+impl From<f32> for Value {
+    fn from(value: f32) -> Self {
+        Value::Float((value as f64 * 100.0).round() / 100.0)
+    }
+}
+
+impl From<f64> for Value {
+    fn from(value: f64) -> Self {
+        Value::Float(value)
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Value::Text(value)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Value::Text(value.to_string())
+    }
+}
+
+impl From<&String> for Value {
+    fn from(value: &String) -> Self {
+        Value::Text(value.clone())
+    }
+}
+
+impl<T: Into<Value>> From<Option<T>> for Value {
+    fn from(value: Option<T>) -> Self {
+        value.map(Into::into).unwrap_or(Value::Null)
+    }
+}
+
+impl<T: Into<Value>> From<Vec<T>> for Value {
+    fn from(items: Vec<T>) -> Self {
+        Value::List(items.into_iter().map(Into::into).collect())
+    }
+}
+
+impl From<Fields> for Value {
+    fn from(fields: Fields) -> Self {
+        Value::Map(fields)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct SystemInfo {
     pub user: user::UserInfo,
     pub os: os::OsInfo,
+    pub host: host::HostInfo,
     pub kernel: kernel::KernelInfo,
     pub cpu: cpu::CpuInfo,
     pub gpu: gpu::GpuInfo,
     pub memory: memory::MemoryInfo,
     pub disk: disk::DiskInfo,
     pub uptime: uptime::UptimeInfo,
+    pub load: load::LoadInfo,
     pub shell: shell::ShellInfo,
     pub desktop: desktop::DesktopInfo,
     pub display: display::DisplayInfo,
     pub battery: battery::BatteryInfo,
     pub network: network::NetworkInfo,
     pub packages: packages::PackageInfo,
+    pub virtualization: virtualization::VirtualizationInfo,
 }
 
-pub fn collect_all_info() -> SystemInfo {
-    let mut sys = System::new_all();
+pub fn collect_all_info(sys: &mut System) -> SystemInfo {
     sys.refresh_all();
     
     SystemInfo {
         user: user::get_info(),
         os: os::get_info(),
+        host: host::get_info(),
         kernel: kernel::get_info(),
-        cpu: cpu::get_info(&sys),
+        cpu: cpu::get_info(sys),
         gpu: gpu::get_info(),
-        memory: memory::get_info(&sys),
-        disk: disk::get_info(&sys),
+        memory: memory::get_info(sys),
+        disk: disk::get_info(sys),
         uptime: uptime::get_info(),
+        load: load::get_info(),
         shell: shell::get_info(),
         desktop: desktop::get_info(),
         display: display::get_info(),
         battery: battery::get_info(),
         network: network::get_info(),
         packages: packages::get_info(),
+        virtualization: virtualization::get_info(),
+    }
+}
+
+impl SystemInfo {
+    pub fn refresh(&mut self, sys: &mut System, elapsed: Duration) {
+        sys.refresh_cpu_usage();
+        sys.refresh_cpu_frequency();
+        sys.refresh_memory();
+        
+        self.cpu = cpu::get_info(sys);
+        self.memory = memory::get_info(sys);
+        self.disk = disk::get_info(sys);
+        self.uptime = uptime::get_info();
+        self.load = load::get_info();
+        self.battery = battery::get_info();
+        
+        let mut network = network::get_info();
+        network.update_rates(&self.network, elapsed.as_secs_f64());
+        self.network = network;
+    }
+    
+    pub fn fields(&self, module: &str) -> Option<Fields> {
+        match module {
+            "user" => Some(self.user.fields()),
+            "os" => Some(self.os.fields()),
+            "host" => Some(self.host.fields()),
+            "kernel" => Some(self.kernel.fields()),
+            "virtualization" => Some(self.virtualization.fields()),
+            "cpu" => Some(self.cpu.fields()),
+            "gpu" => Some(self.gpu.fields()),
+            "memory" => Some(self.memory.fields()),
+            "disk" => Some(self.disk.fields()),
+            "uptime" => Some(self.uptime.fields()),
+            "load" => Some(self.load.fields()),
+            "shell" => Some(self.shell.fields()),
+            "terminal" => Some(self.shell.terminal_fields()),
+            "desktop" => Some(self.desktop.fields()),
+            "display" => Some(self.display.fields()),
+            "battery" => Some(self.battery.fields()),
+            "packages" => Some(self.packages.fields()),
+            "network" => Some(self.network.fields()),
+            _ => None,
+        }
+    }
+    
+    pub fn all_fields(&self) -> Vec<(&'static str, Fields)> {
+        MODULES.iter()
+            .filter_map(|module| Some((*module, self.fields(module)?)))
+            .collect()
+    }
+    
+    pub fn display(&self, module: &str) -> Option<String> {
+        match module {
+            "user" => Some(self.user.display()),
+            "os" => Some(self.os.display()),
+            "host" => Some(self.host.display()).filter(|_| self.host.has_host()),
+            "kernel" => Some(self.kernel.display()),
+            "virtualization" => Some(self.virtualization.display()).filter(|_| self.virtualization.is_detected()),
+            "cpu" => Some(self.cpu.display()),
+            "gpu" => Some(self.gpu.display()).filter(|_| !self.gpu.gpus.is_empty()),
+            "memory" => Some(self.memory.display()),
+            "disk" => Some(self.disk.display()),
+            "uptime" => Some(self.uptime.display()),
+            "load" => self.load.load_values().map(|values| {
+                values.iter().map(|value| format!("{:.2}", value)).collect::<Vec<_>>().join(", ")
+            }),
+            "shell" => Some(self.shell.display()),
+            "terminal" => Some(self.shell.display_terminal()),
+            "desktop" => Some(self.desktop.display()).filter(|_| self.desktop.desktop_env != "Unknown"),
+            "display" => self.display.primary().map(|display| display.display()),
+            "battery" => self.battery.display(),
+            "packages" => Some(self.packages.display()).filter(|_| self.packages.total > 0),
+            "network" => Some(self.network.display()),
+            _ => None,
+        }
     }
 }
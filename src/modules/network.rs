@@ -18,11 +18,15 @@
 use std::fs;
 use std::net::IpAddr;
 use std::process::Command;
+use super::Fields;
+use super::memory::format_bytes;
 
 #[derive(Debug, Clone)]
 pub struct NetworkInfo {
     pub interfaces: Vec<NetworkInterface>,
     pub local_ip: Option<String>,
+    pub rx_rate: Option<f64>,
+    pub tx_rate: Option<f64>,
 }
 
 #[derive(Debug, Clone)]
@@ -32,6 +36,8 @@ pub struct NetworkInterface {
     pub mac_address: Option<String>,
     pub interface_type: InterfaceType,
     pub is_up: bool,
+    pub rx_bytes: u64,
+    pub tx_bytes: u64,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -71,6 +77,13 @@ pub fn get_info() -> NetworkInfo {
             
             let ip_addresses = get_ip_addresses(&name);
             
+            let read_counter = |counter: &str| {
+                fs::read_to_string(path.join("statistics").join(counter))
+                    .ok()
+                    .and_then(|s| s.trim().parse().ok())
+                    .unwrap_or(0)
+            };
+            
             if is_up && local_ip.is_none() {
                 if let Some(ip) = ip_addresses.first() {
                     local_ip = Some(ip.clone());
@@ -83,6 +96,8 @@ pub fn get_info() -> NetworkInfo {
                 mac_address,
                 interface_type,
                 is_up,
+                rx_bytes: read_counter("rx_bytes"),
+                tx_bytes: read_counter("tx_bytes"),
             });
         }
     }
@@ -94,6 +109,8 @@ pub fn get_info() -> NetworkInfo {
     NetworkInfo {
         interfaces,
         local_ip,
+        rx_rate: None,
+        tx_rate: None,
     }
 }
 
@@ -148,6 +165,31 @@ fn get_ip_addresses(interface: &str) -> Vec<String> {
 }
 
 impl NetworkInfo {
+    pub fn update_rates(&mut self, previous: &NetworkInfo, elapsed_secs: f64) {
+        if elapsed_secs <= 0.0 {
+            return;
+        }
+        
+        let (mut rx, mut tx) = (0, 0);
+        for interface in &self.interfaces {
+            if let Some(old) = previous.interfaces.iter().find(|i| i.name == interface.name) {
+                rx += interface.rx_bytes.saturating_sub(old.rx_bytes);
+                tx += interface.tx_bytes.saturating_sub(old.tx_bytes);
+            }
+        }
+        
+        self.rx_rate = Some(rx as f64 / elapsed_secs);
+        self.tx_rate = Some(tx as f64 / elapsed_secs);
+    }
+    
+    pub fn display_throughput(&self) -> Option<String> {
+        Some(format!(
+            "↓ {}/s  ↑ {}/s",
+            format_bytes(self.rx_rate? as u64),
+            format_bytes(self.tx_rate? as u64)
+        ))
+    }
+    
     pub fn display(&self) -> String {
         self.local_ip.clone().unwrap_or_else(|| "Not connected".to_string())
     }
@@ -159,6 +201,15 @@ impl NetworkInfo {
             .map(|i| i.display())
             .collect()
     }
+    
+    pub fn fields(&self) -> Fields {
+        vec![
+            ("local_ip", self.local_ip.clone().into()),
+            ("rx_rate", self.rx_rate.into()),
+            ("tx_rate", self.tx_rate.into()),
+            ("interfaces", self.interfaces.iter().map(|i| i.fields()).collect::<Vec<_>>().into()),
+        ]
+    }
 }
 
 impl NetworkInterface {
@@ -179,4 +230,23 @@ impl NetworkInterface {
             format!("{}: No IP", self.name)
         }
     }
+    
+    pub fn fields(&self) -> Fields {
+        let kind = match self.interface_type {
+            InterfaceType::Ethernet => "ethernet",
+            InterfaceType::Wireless => "wireless",
+            InterfaceType::Loopback => "loopback",
+            InterfaceType::Virtual => "virtual",
+            InterfaceType::Unknown => "unknown",
+        };
+        vec![
+            ("name", self.name.as_str().into()),
+            ("type", kind.into()),
+            ("ips", self.ip_addresses.clone().into()),
+            ("mac", self.mac_address.clone().into()),
+            ("up", self.is_up.into()),
+            ("rx_bytes", self.rx_bytes.into()),
+            ("tx_bytes", self.tx_bytes.into()),
+        ]
+    }
 }
@@ -17,19 +17,27 @@
 
 use std::fs;
 use std::collections::HashMap;
+use std::path::Path;
+use super::Fields;
 
 #[derive(Debug, Clone)]
 pub struct OsInfo {
     pub name: String,
     pub distro_id: String,
+    pub id_like: Vec<String>,
     pub version: String,
     pub codename: String,
+    pub variant: Option<String>,
+    pub variant_id: Option<String>,
+    pub build_id: Option<String>,
+    pub immutable: bool,
+    pub nixos_generation: Option<u32>,
     pub arch: String,
     pub pretty_name: String,
 }
 
 pub fn get_info() -> OsInfo {
-    let os_release = parse_os_release();
+    let os_release = read_release_info();
     let arch = std::env::consts::ARCH.to_string();
     
     let name = os_release.get("NAME")
@@ -41,6 +49,10 @@ pub fn get_info() -> OsInfo {
         .unwrap_or_else(|| "linux".to_string())
         .to_lowercase();
     
+    let id_like = os_release.get("ID_LIKE")
+        .map(|like| like.split_whitespace().map(|id| id.to_lowercase()).collect())
+        .unwrap_or_default();
+    
     let version = os_release.get("VERSION_ID")
         .or_else(|| os_release.get("VERSION"))
         .cloned()
@@ -52,37 +64,193 @@ pub fn get_info() -> OsInfo {
     
     let pretty_name = os_release.get("PRETTY_NAME")
         .cloned()
-        .unwrap_or_else(|| format!("{} {}", name, version));
+        .unwrap_or_else(|| format!("{} {}", name, version).trim().to_string());
+    
+    let variant_id = os_release.get("VARIANT_ID").cloned();
+    let immutable = Path::new("/run/ostree-booted").exists()
+        || matches!(variant_id.as_deref(), Some("silverblue" | "kinoite" | "sericea" | "onyx" | "coreos" | "iot"))
+        || distro_id == "nixos";
+    
+    let nixos_generation = if distro_id == "nixos" {
+        read_nixos_generation()
+    } else {
+        None
+    };
     
     OsInfo {
         name,
         distro_id,
+        id_like,
         version,
         codename,
+        variant: os_release.get("VARIANT").cloned(),
+        variant_id,
+        build_id: os_release.get("BUILD_ID").or_else(|| os_release.get("IMAGE_VERSION")).cloned(),
+        immutable,
+        nixos_generation,
         arch,
         pretty_name,
     }
 }
 
-fn parse_os_release() -> HashMap<String, String> {
-    let mut map = HashMap::new();
-    
+fn read_release_info() -> HashMap<String, String> {
     let content = fs::read_to_string("/etc/os-release")
-        .or_else(|_| fs::read_to_string("/usr/lib/os-release"))
-        .unwrap_or_default();
+        .or_else(|_| fs::read_to_string("/usr/lib/os-release"));
+    
+    if let Ok(content) = content {
+        let map = parse_os_release(&content);
+        if map.contains_key("ID") || map.contains_key("NAME") {
+            return map;
+        }
+    }
+    
+    if let Ok(content) = fs::read_to_string("/etc/lsb-release") {
+        let lsb = parse_os_release(&content);
+        if let Some(id) = lsb.get("DISTRIB_ID") {
+            let mut map = HashMap::new();
+            map.insert("NAME".to_string(), id.clone());
+            map.insert("ID".to_string(), id.to_lowercase().replace(' ', ""));
+            if let Some(release) = lsb.get("DISTRIB_RELEASE") {
+                map.insert("VERSION_ID".to_string(), release.clone());
+            }
+            if let Some(codename) = lsb.get("DISTRIB_CODENAME") {
+                map.insert("VERSION_CODENAME".to_string(), codename.clone());
+            }
+            if let Some(description) = lsb.get("DISTRIB_DESCRIPTION") {
+                map.insert("PRETTY_NAME".to_string(), description.clone());
+            }
+            return map;
+        }
+    }
+    
+    read_distro_release_file().unwrap_or_default()
+}
+
+fn read_distro_release_file() -> Option<HashMap<String, String>> {
+    let (id, name, version) = if Path::new("/etc/arch-release").exists() {
+        ("arch", "Arch Linux", String::new())
+    } else if let Ok(content) = fs::read_to_string("/etc/gentoo-release") {
+        let version = content.split_whitespace().last().unwrap_or_default().to_string();
+        ("gentoo", "Gentoo", version)
+    } else if let Ok(content) = fs::read_to_string("/etc/alpine-release") {
+        ("alpine", "Alpine Linux", content.trim().to_string())
+    } else if let Ok(content) = fs::read_to_string("/etc/slackware-version") {
+        let version = content.split_whitespace().last().unwrap_or_default().to_string();
+        ("slackware", "Slackware", version)
+    } else if let Ok(content) = fs::read_to_string("/etc/debian_version") {
+        ("debian", "Debian GNU/Linux", content.trim().to_string())
+    } else {
+        return None;
+    };
+    
+    let mut map = HashMap::new();
+    map.insert("ID".to_string(), id.to_string());
+    map.insert("NAME".to_string(), name.to_string());
+    map.insert("PRETTY_NAME".to_string(), format!("{} {}", name, version).trim().to_string());
+    if !version.is_empty() {
+        map.insert("VERSION_ID".to_string(), version);
+    }
+    Some(map)
+}
+
+pub fn parse_os_release(content: &str) -> HashMap<String, String> {
+    let mut map = HashMap::new();
     
     for line in content.lines() {
-        if let Some((key, value)) = line.split_once('=') {
-            let value = value.trim_matches('"').to_string();
-            map.insert(key.to_string(), value);
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
         }
+        
+        let Some((key, raw_value)) = line.split_once('=') else {
+            continue;
+        };
+        
+        let key = key.trim();
+        if key.is_empty() || !key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            continue;
+        }
+        
+        map.insert(key.to_string(), unquote_value(raw_value.trim()));
     }
     
     map
 }
 
+fn unquote_value(raw: &str) -> String {
+    let mut result = String::new();
+    let mut chars = raw.chars();
+    let mut quote: Option<char> = None;
+    
+    while let Some(ch) = chars.next() {
+        match (quote, ch) {
+            (None, '"') | (None, '\'') => quote = Some(ch),
+            (Some(q), c) if c == q => quote = None,
+            (None, '#') if result.is_empty() || result.ends_with(char::is_whitespace) => break,
+            (Some('"'), '\\') | (None, '\\') => {
+                match chars.next() {
+                    Some(escaped @ ('"' | '\\' | '$' | '`' | '\'')) => result.push(escaped),
+                    Some(other) => {
+                        result.push('\\');
+                        result.push(other);
+                    }
+                    None => result.push('\\'),
+                }
+            }
+            (_, c) => result.push(c),
+        }
+    }
+    
+    result.trim_end().to_string()
+}
+
+fn read_nixos_generation() -> Option<u32> {
+    let target = fs::read_link("/nix/var/nix/profiles/system").ok()?;
+    let link = target.file_name()?.to_string_lossy().to_string();
+    link.strip_prefix("system-")?
+        .strip_suffix("-link")?
+        .parse()
+        .ok()
+}
+
 impl OsInfo {
     pub fn display(&self) -> String {
-        format!("{} {}", self.pretty_name, self.arch)
+        let mut result = format!("{} {}", self.pretty_name, self.arch);
+        
+        if let Some(generation) = self.nixos_generation {
+            result.push_str(&format!(" (generation {})", generation));
+        } else if self.immutable && !self.pretty_name.contains('(') {
+            if let Some(variant) = &self.variant {
+                result.push_str(&format!(" ({})", variant));
+            }
+        }
+        
+        result
+    }
+    
+    pub fn logo_candidates(&self) -> Vec<String> {
+        let mut candidates = vec![self.distro_id.clone()];
+        if let Some(variant_id) = &self.variant_id {
+            candidates.insert(0, format!("{}_{}", self.distro_id, variant_id));
+        }
+        candidates.extend(self.id_like.iter().cloned());
+        candidates
+    }
+    
+    pub fn fields(&self) -> Fields {
+        vec![
+            ("name", self.name.as_str().into()),
+            ("pretty_name", self.pretty_name.as_str().into()),
+            ("id", self.distro_id.as_str().into()),
+            ("id_like", self.id_like.clone().into()),
+            ("version", self.version.as_str().into()),
+            ("codename", self.codename.as_str().into()),
+            ("variant", self.variant.clone().into()),
+            ("variant_id", self.variant_id.clone().into()),
+            ("build_id", self.build_id.clone().into()),
+            ("immutable", self.immutable.into()),
+            ("nixos_generation", self.nixos_generation.into()),
+            ("arch", self.arch.as_str().into()),
+        ]
     }
 }
@@ -18,6 +18,7 @@
 use std::process::Command;
 use std::fs;
 use std::path::Path;
+use super::Fields;
 
 #[derive(Debug, Clone)]
 pub struct PackageInfo {
@@ -287,4 +288,15 @@ impl PackageInfo {
     pub fn display_total(&self) -> String {
         format!("{}", self.total)
     }
+    
+    pub fn fields(&self) -> Fields {
+        let managers: Vec<Fields> = self.managers.iter()
+            .map(|m| vec![("name", m.name.as_str().into()), ("count", m.count.into())])
+            .collect();
+        vec![
+            ("total", self.total.into()),
+            ("summary", self.display().into()),
+            ("managers", managers.into()),
+        ]
+    }
 }
@@ -16,75 +16,327 @@
 */
 
 use std::env;
-use std::process::Command;
 use std::fs;
+use std::io::Read;
+use std::path::Path;
+use users::os::unix::UserExt;
+use super::Fields;
+
+const SCAN_CHUNK_SIZE: usize = 64 * 1024;
+const SCAN_OVERLAP: usize = 256;
+const SCAN_LIMIT: usize = 64 * 1024 * 1024;
 
 #[derive(Debug, Clone)]
 pub struct ShellInfo {
     pub name: String,
     pub version: Option<String>,
     pub path: String,
+    pub login_shell: String,
+    pub login_path: String,
     pub terminal: String,
+    pub terminal_version: Option<String>,
+    pub terminal_font: Option<String>,
+    pub multiplexer: Option<String>,
+    pub over_ssh: bool,
 }
 
 pub fn get_info() -> ShellInfo {
-    let shell_path = env::var("SHELL").unwrap_or_else(|_| "/bin/sh".to_string());
-    let shell_name = shell_path.split('/').last()
-        .unwrap_or("sh")
-        .to_string();
+    let login_path = detect_login_shell();
+    let login_shell = executable_name(&login_path);
+    
+    let (shell_name, shell_path) = detect_running_shell()
+        .unwrap_or_else(|| (login_shell.clone(), login_path.clone()));
     
-    let version = get_shell_version(&shell_name);
-    let terminal = detect_terminal();
+    let version = get_shell_version(&shell_name, &shell_path);
+    let chain = detect_terminal();
+    let terminal = chain.terminal
+        .unwrap_or_else(|| env::var("TERM").unwrap_or_else(|_| "unknown".to_string()));
+    let terminal_version = detect_terminal_version(&terminal);
+    let terminal_font = detect_terminal_font(&terminal);
     
     ShellInfo {
         name: shell_name,
         version,
         path: shell_path,
+        login_shell,
+        login_path,
         terminal,
+        terminal_version,
+        terminal_font,
+        multiplexer: chain.multiplexer,
+        over_ssh: chain.over_ssh,
     }
 }
 
-fn get_shell_version(shell: &str) -> Option<String> {
-    let output = match shell {
-        "bash" => Command::new("bash").arg("--version").output().ok()?,
-        "zsh" => Command::new("zsh").arg("--version").output().ok()?,
-        "fish" => Command::new("fish").arg("--version").output().ok()?,
-        "nu" | "nushell" => Command::new("nu").arg("--version").output().ok()?,
-        "pwsh" | "powershell" => Command::new("pwsh").arg("--version").output().ok()?,
-        _ => return None,
-    };
+fn detect_login_shell() -> String {
+    if let Ok(shell) = env::var("SHELL") {
+        if !shell.is_empty() {
+            return shell;
+        }
+    }
     
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let first_line = stdout.lines().next()?;
+    users::get_user_by_uid(users::get_current_uid())
+        .map(|user| user.shell().to_string_lossy().to_string())
+        .unwrap_or_else(|| "/bin/sh".to_string())
+}
+
+fn executable_name(path: &str) -> String {
+    path.rsplit('/').next()
+        .filter(|name| !name.is_empty())
+        .unwrap_or("sh")
+        .to_string()
+}
+
+fn detect_running_shell() -> Option<(String, String)> {
+    let mut pid = get_parent_pid(std::process::id())?;
+    let mut depth = 0;
     
-    let version = match shell {
-        "bash" => {
-            
-            first_line.split("version ").nth(1)?
-                .split(|c: char| !c.is_numeric() && c != '.').next()?
-                .to_string()
+    while pid > 1 && depth < 64 {
+        let name = get_process_name(pid)?;
+        let name = name.trim_start_matches('-').to_string();
+        
+        if SHELLS.contains(&name.as_str()) {
+            let path = fs::read_link(format!("/proc/{}/exe", pid))
+                .map(|p| p.to_string_lossy().to_string())
+                .ok()
+                .or_else(|| find_in_path(&name))
+                .unwrap_or_else(|| name.clone());
+            return Some((name, path));
         }
-        "zsh" => {
-            
-            first_line.split_whitespace().nth(1)?.to_string()
+        
+        if terminal_name(&name).is_some() || multiplexer_name(&name).is_some() {
+            return None;
         }
-        "fish" => {
+        
+        pid = get_parent_pid(pid)?;
+        depth += 1;
+    }
+    
+    None
+}
+
+fn find_in_path(name: &str) -> Option<String> {
+    env::var_os("PATH").and_then(|paths| {
+        env::split_paths(&paths)
+            .map(|dir| dir.join(name))
+            .find(|candidate| candidate.is_file())
+            .map(|candidate| candidate.to_string_lossy().to_string())
+    })
+}
+
+fn get_shell_version(shell: &str, path: &str) -> Option<String> {
+    version_from_env(shell)
+        .or_else(|| version_from_binary(shell, path))
+        .or_else(|| version_from_package_db(shell))
+}
+
+fn version_from_env(shell: &str) -> Option<String> {
+    let var = match shell {
+        "bash" => "BASH_VERSION",
+        "zsh" => "ZSH_VERSION",
+        "fish" => "FISH_VERSION",
+        "ksh" | "mksh" => "KSH_VERSION",
+        "xonsh" => "XONSH_VERSION",
+        "nu" => "NU_VERSION",
+        "oil" | "osh" | "ysh" => "OIL_VERSION",
+        _ => return None,
+    };
+    
+    let value = env::var(var).ok()?;
+    match shell {
+        "bash" => value.split('(').next().map(|v| v.to_string()),
+        "ksh" | "mksh" => clean_ksh_version(&value),
+        _ => Some(value),
+    }
+}
+
+fn clean_ksh_version(raw: &str) -> Option<String> {
+    let raw = raw.trim_start_matches("@(#)");
+    if let Some(rest) = raw.strip_prefix("MIRBSD KSH ") {
+        return rest.split_whitespace().next().map(|v| v.to_string());
+    }
+    raw.split_whitespace()
+        .find(|part| part.starts_with("93"))
+        .or_else(|| raw.split_whitespace().last())
+        .map(|v| v.trim_end_matches('$').to_string())
+}
+
+fn version_from_binary(shell: &str, path: &str) -> Option<String> {
+    let marker: &[u8] = match shell {
+        "bash" => b"@(#)Bash version ",
+        "zsh" => b"zsh-",
+        "fish" => b"fish, version ",
+        "ksh" => b"@(#)$Id: Version ",
+        "mksh" => b"@(#)MIRBSD KSH ",
+        "tcsh" | "csh" => b"tcsh ",
+        "elvish" => b"src.elv.sh/pkg/buildinfo.Version=",
+        _ => return None,
+    };
+    
+    if !Path::new(path).is_absolute() {
+        return None;
+    }
+    
+    let mut file = fs::File::open(path).ok()?;
+    let mut chunk = vec![0u8; SCAN_CHUNK_SIZE];
+    let mut window: Vec<u8> = Vec::with_capacity(SCAN_CHUNK_SIZE + SCAN_OVERLAP);
+    let mut scanned = 0;
+    
+    loop {
+        let read = file.read(&mut chunk).ok()?;
+        let done = read == 0 || scanned + read >= SCAN_LIMIT;
+        window.extend_from_slice(&chunk[..read]);
+        scanned += read;
+        
+        let mut offset = 0;
+        while let Some(pos) = find_bytes(&window[offset..], marker) {
+            let start = offset + pos + marker.len();
+            let end = match window[start..].iter().position(|b| !b.is_ascii_graphic() && *b != b' ') {
+                Some(p) => start + p,
+                None if done => window.len(),
+                None => break,
+            };
+            let candidate = String::from_utf8_lossy(&window[start..end]).to_string();
             
-            first_line.split("version ").nth(1)?.to_string()
+            if let Some(version) = clean_binary_version(shell, &candidate) {
+                return Some(version);
+            }
+            offset = start;
         }
-        "nu" | "nushell" => {
-            first_line.trim().to_string()
+        
+        if done {
+            return None;
         }
-        _ => first_line.to_string(),
+        let keep = window.len().saturating_sub(SCAN_OVERLAP + marker.len());
+        window.drain(..keep);
+    }
+}
+
+fn clean_binary_version(shell: &str, candidate: &str) -> Option<String> {
+    let version = match shell {
+        "bash" => candidate.split('(').next()?.to_string(),
+        "zsh" => candidate.split('-').next()?.to_string(),
+        "ksh" => candidate.split_whitespace().find(|p| p.starts_with("93"))?.to_string(),
+        "mksh" => candidate.split_whitespace().next()?.to_string(),
+        _ => candidate.split_whitespace().next()?.to_string(),
+    };
+    
+    let plausible = match shell {
+        "mksh" => version.starts_with('R'),
+        _ => version.starts_with(|c: char| c.is_ascii_digit()),
     };
     
-    Some(version)
+    if plausible && !version.contains('%') {
+        Some(version)
+    } else {
+        None
+    }
 }
 
-fn detect_terminal() -> String {
+fn find_bytes(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|window| window == needle)
+}
+
+fn version_from_package_db(shell: &str) -> Option<String> {
+    let package = match shell {
+        "sh" => return None,
+        "nu" => "nushell",
+        "pwsh" => "powershell",
+        "oil" | "osh" | "ysh" => "oils-for-unix",
+        "csh" => "tcsh",
+        other => other,
+    };
+    
+    if let Ok(entries) = fs::read_dir("/var/lib/pacman/local") {
+        for entry in entries.flatten() {
+            let dir = entry.file_name().to_string_lossy().to_string();
+            let Some(rest) = dir.strip_prefix(package).and_then(|r| r.strip_prefix('-')) else {
+                continue;
+            };
+            if let Some((version, _pkgrel)) = rest.rsplit_once('-') {
+                if version.starts_with(|c: char| c.is_ascii_digit()) {
+                    return Some(version.to_string());
+                }
+            }
+        }
+    }
+    
+    let status = fs::read_to_string("/var/lib/dpkg/status").ok()?;
+    let header = format!("Package: {}\n", package);
+    let start = status.find(&header)?;
+    let stanza = status[start..].split("\n\n").next()?;
+    let version = stanza.lines().find_map(|line| line.strip_prefix("Version: "))?;
+    let version = version.split_once(':').map(|(_, v)| v).unwrap_or(version);
+    let version = version.rsplit_once('-').map(|(v, _)| v).unwrap_or(version);
+    Some(version.to_string())
+}
+
+const SHELLS: &[&str] = &[
+    "bash", "zsh", "fish", "sh", "dash", "ksh", "mksh", "tcsh", "csh",
+    "nu", "elvish", "xonsh", "oil", "osh", "ysh", "pwsh", "ion",
+];
+
+const PRIVILEGE_WRAPPERS: &[&str] = &["sudo", "sudo-rs", "doas", "su", "run0"];
+
+const SESSION_ROOTS: &[&str] = &["login", "agetty", "getty", "systemd", "init"];
+
+const KNOWN_TERMINALS: &[(&str, &str)] = &[
+    ("kitty", "kitty"),
+    ("alacritty", "Alacritty"),
+    ("wezterm-gui", "WezTerm"),
+    ("wezterm", "WezTerm"),
+    ("foot", "foot"),
+    ("footclient", "foot"),
+    ("konsole", "Konsole"),
+    ("gnome-terminal-", "GNOME Terminal"),
+    ("gnome-terminal", "GNOME Terminal"),
+    ("kgx", "GNOME Console"),
+    ("ptyxis-agent", "Ptyxis"),
+    ("ptyxis", "Ptyxis"),
+    ("xfce4-terminal", "Xfce Terminal"),
+    ("mate-terminal", "MATE Terminal"),
+    ("lxterminal", "LXTerminal"),
+    ("qterminal", "QTerminal"),
+    ("terminator", "Terminator"),
+    ("tilix", "Tilix"),
+    ("ghostty", "Ghostty"),
+    ("contour", "Contour"),
+    ("rio", "Rio"),
+    ("blackbox", "Black Box"),
+    ("terminology", "Terminology"),
+    ("cool-retro-term", "cool-retro-term"),
+    ("xterm", "xterm"),
+    ("urxvt", "urxvt"),
+    ("urxvtd", "urxvt"),
+    ("st", "st"),
+    ("hyper", "Hyper"),
+    ("tabby", "Tabby"),
+    ("code", "VS Code"),
+    ("warp", "Warp"),
+];
+
+struct TerminalChain {
+    terminal: Option<String>,
+    multiplexer: Option<String>,
+    over_ssh: bool,
+}
+
+fn detect_terminal() -> TerminalChain {
+    let mut chain = walk_process_ancestry();
+    
+    if chain.multiplexer.is_none() {
+        chain.multiplexer = detect_multiplexer_from_env();
+    }
+    
+    if chain.terminal.is_some() {
+        return chain;
+    }
     
     if let Ok(term_program) = env::var("TERM_PROGRAM") {
-        return term_program;
+        if !matches!(term_program.as_str(), "tmux" | "screen" | "zellij") {
+            chain.terminal = Some(term_program);
+            return chain;
+        }
     }
     
     let possible_terms = [
@@ -96,32 +348,298 @@ fn detect_terminal() -> String {
         ("TERMINATOR_UUID", "Terminator"),
         ("TILIX_ID", "Tilix"),
         ("ITERM_SESSION_ID", "iTerm2"),
+        ("GHOSTTY_RESOURCES_DIR", "Ghostty"),
     ];
     
     for (env_var, term_name) in &possible_terms {
         if env::var(env_var).is_ok() {
-            return term_name.to_string();
+            chain.terminal = Some(term_name.to_string());
+            return chain;
         }
     }
     
-    if let Some(ppid) = get_parent_pid() {
-        if let Some(name) = get_process_name(ppid) {
-            let known_terms = ["kitty", "alacritty", "konsole", "gnome-terminal", 
-                              "xterm", "urxvt", "terminator", "tilix", "wezterm",
-                              "st", "foot", "contour", "hyper"];
-            for term in &known_terms {
-                if name.to_lowercase().contains(term) {
-                    return name;
+    if chain.over_ssh || env::var("SSH_TTY").is_ok() {
+        chain.over_ssh = true;
+        chain.terminal = Some("SSH".to_string());
+    }
+    
+    chain
+}
+
+fn walk_process_ancestry() -> TerminalChain {
+    let mut chain = TerminalChain {
+        terminal: None,
+        multiplexer: None,
+        over_ssh: false,
+    };
+    
+    let mut pid = match get_parent_pid(std::process::id()) {
+        Some(pid) => pid,
+        None => return chain,
+    };
+    let mut visited = Vec::new();
+    
+    while pid > 1 && !visited.contains(&pid) && visited.len() < 64 {
+        visited.push(pid);
+        
+        let Some(name) = get_process_name(pid) else {
+            break;
+        };
+        
+        if let Some(mux) = multiplexer_name(&name) {
+            chain.multiplexer.get_or_insert_with(|| mux.to_string());
+            match find_multiplexer_client(mux, pid) {
+                Some(client_pid) => {
+                    pid = match get_parent_pid(client_pid) {
+                        Some(ppid) => ppid,
+                        None => break,
+                    };
+                    continue;
                 }
+                None => break,
             }
         }
+        
+        if name == "sshd" || name.starts_with("sshd-") {
+            chain.over_ssh = true;
+            break;
+        }
+        
+        if SESSION_ROOTS.contains(&name.as_str()) {
+            break;
+        }
+        
+        if let Some(terminal) = terminal_name(&name) {
+            chain.terminal = Some(terminal);
+            break;
+        }
+        
+        pid = match get_parent_pid(pid) {
+            Some(ppid) => ppid,
+            None => break,
+        };
+    }
+    
+    chain
+}
+
+fn multiplexer_name(process: &str) -> Option<&'static str> {
+    if process.starts_with("tmux") {
+        Some("tmux")
+    } else if process.eq_ignore_ascii_case("screen") {
+        Some("screen")
+    } else if process == "zellij" {
+        Some("zellij")
+    } else {
+        None
+    }
+}
+
+fn find_multiplexer_client(mux: &str, server_pid: u32) -> Option<u32> {
+    let uid = users::get_current_uid();
+    let session = match mux {
+        "tmux" => env::var("TMUX").ok()
+            .and_then(|tmux| tmux.split(',').next().map(str::to_string)),
+        "screen" => env::var("STY").ok()
+            .and_then(|sty| sty.split_once('.').map(|(_, name)| name.to_string())),
+        "zellij" => env::var("ZELLIJ_SESSION_NAME").ok(),
+        _ => None,
+    };
+    
+    let mut candidates = Vec::new();
+    let entries = fs::read_dir("/proc").ok()?;
+    for entry in entries.flatten() {
+        let Ok(pid) = entry.file_name().to_string_lossy().parse::<u32>() else {
+            continue;
+        };
+        if pid == server_pid {
+            continue;
+        }
+        
+        let Some(name) = get_process_name(pid) else {
+            continue;
+        };
+        if multiplexer_name(&name) != Some(mux) || name.contains("server") {
+            continue;
+        }
+        
+        if get_process_uid(pid) != Some(uid) {
+            continue;
+        }
+        
+        let parent_is_mux = get_parent_pid(pid)
+            .and_then(get_process_name)
+            .is_some_and(|parent| multiplexer_name(&parent) == Some(mux));
+        if !parent_is_mux {
+            candidates.push(pid);
+        }
+    }
+    
+    if let Some(session) = session.filter(|s| !s.is_empty()) {
+        let attached = candidates.iter().copied().find(|pid| {
+            let cmdline = get_process_cmdline(*pid);
+            match mux {
+                "tmux" => tmux_socket_path(&cmdline, uid) == session,
+                _ => cmdline.iter().any(|arg| arg.contains(session.as_str())),
+            }
+        });
+        if attached.is_some() {
+            return attached;
+        }
+    }
+    
+    match candidates.as_slice() {
+        [pid] => Some(*pid),
+        _ => None,
+    }
+}
+
+fn tmux_socket_path(cmdline: &[String], uid: u32) -> String {
+    let socket_dir = format!("{}/tmux-{}", env::var("TMUX_TMPDIR").unwrap_or_else(|_| "/tmp".to_string()), uid);
+    let mut name = "default".to_string();
+    
+    let mut args = cmdline.iter().skip(1);
+    while let Some(arg) = args.next() {
+        let (flag, inline) = match arg.strip_prefix('-') {
+            Some(flags) if flags.starts_with(['S', 'L']) => flags.split_at(1),
+            _ => continue,
+        };
+        let value = if inline.is_empty() { args.next().cloned() } else { Some(inline.to_string()) };
+        match (flag, value) {
+            ("S", Some(path)) => return path,
+            ("L", Some(label)) => name = label,
+            _ => {}
+        }
+    }
+    
+    format!("{}/{}", socket_dir, name)
+}
+
+fn detect_multiplexer_from_env() -> Option<String> {
+    if env::var("TMUX").is_ok() {
+        Some("tmux".to_string())
+    } else if env::var("STY").is_ok() {
+        Some("screen".to_string())
+    } else if env::var("ZELLIJ").is_ok() {
+        Some("zellij".to_string())
+    } else {
+        None
+    }
+}
+
+fn terminal_name(process: &str) -> Option<String> {
+    if SHELLS.contains(&process) || PRIVILEGE_WRAPPERS.contains(&process) {
+        return None;
+    }
+    
+    KNOWN_TERMINALS.iter()
+        .find(|(comm, _)| {
+            process == *comm || (comm.ends_with('-') && process.starts_with(comm))
+        })
+        .map(|(_, name)| name.to_string())
+}
+
+fn detect_terminal_version(terminal: &str) -> Option<String> {
+    if let Ok(version) = env::var("TERM_PROGRAM_VERSION") {
+        let program = env::var("TERM_PROGRAM").unwrap_or_default();
+        if program.eq_ignore_ascii_case(terminal) || terminal.to_lowercase().contains(&program.to_lowercase()) {
+            return Some(version);
+        }
+    }
+    
+    match terminal {
+        "Konsole" => {
+            let raw = env::var("KONSOLE_VERSION").ok()?;
+            let num: u32 = raw.parse().ok()?;
+            Some(format!("{}.{:02}.{}", num / 10000, (num / 100) % 100, num % 100))
+        }
+        "GNOME Terminal" | "GNOME Console" | "Ptyxis" | "Tilix" | "Xfce Terminal" | "MATE Terminal" | "Terminator" => {
+            let raw = env::var("VTE_VERSION").ok()?;
+            let num: u32 = raw.parse().ok()?;
+            Some(format!("VTE {}.{}.{}", num / 10000, (num / 100) % 100, num % 100))
+        }
+        "xterm" => {
+            let raw = env::var("XTERM_VERSION").ok()?;
+            let start = raw.find('(')? + 1;
+            let end = raw.find(')')?;
+            Some(raw[start..end].to_string())
+        }
+        _ => None,
+    }
+}
+
+fn detect_terminal_font(terminal: &str) -> Option<String> {
+    let config = crate::config::config_home();
+    let home = std::path::PathBuf::from(env::var("HOME").unwrap_or_default());
+    
+    match terminal {
+        "kitty" => {
+            let content = fs::read_to_string(config.join("kitty/kitty.conf")).ok()?;
+            let mut family = None;
+            let mut size = None;
+            for line in content.lines() {
+                let line = line.trim();
+                if let Some(value) = line.strip_prefix("font_family") {
+                    family = Some(value.trim().to_string());
+                } else if let Some(value) = line.strip_prefix("font_size") {
+                    size = Some(value.trim().to_string());
+                }
+            }
+            format_terminal_font(family?, size)
+        }
+        "Alacritty" => {
+            let content = fs::read_to_string(config.join("alacritty/alacritty.toml")).ok()?;
+            let toml = crate::config::parse_toml(&content);
+            let family = toml.get("font.normal")
+                .and_then(|s| s.get("family").cloned())
+                .or_else(|| {
+                    let normal = toml.get("font")?.get("normal")?.clone();
+                    let start = normal.find("family")?;
+                    let quoted = normal[start..].split('"').nth(1)?;
+                    Some(quoted.to_string())
+                });
+            let size = toml.get("font").and_then(|s| s.get("size").cloned());
+            format_terminal_font(family?, size)
+        }
+        "foot" => {
+            let ini = crate::config::parse_ini(&fs::read_to_string(config.join("foot/foot.ini")).ok()?);
+            let font = ini.get("main").or_else(|| ini.get(""))?.get("font")?.clone();
+            let font = font.split(',').next()?;
+            let mut parts = font.split(':');
+            let family = parts.next()?.trim().to_string();
+            let size = parts.find_map(|p| p.trim().strip_prefix("size=").map(|s| s.to_string()));
+            format_terminal_font(family, size)
+        }
+        "WezTerm" => {
+            let content = fs::read_to_string(config.join("wezterm/wezterm.lua"))
+                .or_else(|_| fs::read_to_string(home.join(".wezterm.lua")))
+                .ok()?;
+            let start = content.find("wezterm.font")?;
+            let family = content[start..].split(['"', '\'']).nth(1)?.to_string();
+            let size = content.lines()
+                .find_map(|line| line.trim().strip_prefix("font_size"))
+                .and_then(|rest| rest.trim().strip_prefix('='))
+                .map(|v| v.trim().trim_end_matches(',').to_string());
+            format_terminal_font(family, size)
+        }
+        _ => None,
+    }
+}
+
+fn format_terminal_font(family: String, size: Option<String>) -> Option<String> {
+    let family = family.trim().trim_matches('"').to_string();
+    if family.is_empty() {
+        return None;
     }
     
-    env::var("TERM").unwrap_or_else(|_| "unknown".to_string())
+    match size.and_then(|s| s.parse::<f32>().ok()) {
+        Some(size) => Some(format!("{} ({}pt)", family, size)),
+        None => Some(family),
+    }
 }
 
-fn get_parent_pid() -> Option<u32> {
-    let status = fs::read_to_string("/proc/self/status").ok()?;
+fn get_parent_pid(pid: u32) -> Option<u32> {
+    let status = fs::read_to_string(format!("/proc/{}/status", pid)).ok()?;
     for line in status.lines() {
         if line.starts_with("PPid:") {
             return line.split_whitespace().nth(1)?.parse().ok();
@@ -130,6 +648,24 @@ fn get_parent_pid() -> Option<u32> {
     None
 }
 
+pub fn get_process_uid(pid: u32) -> Option<u32> {
+    let status = fs::read_to_string(format!("/proc/{}/status", pid)).ok()?;
+    status.lines()
+        .find_map(|line| line.strip_prefix("Uid:"))
+        .and_then(|uids| uids.split_whitespace().next()?.parse().ok())
+}
+
+fn get_process_cmdline(pid: u32) -> Vec<String> {
+    fs::read(format!("/proc/{}/cmdline", pid))
+        .map(|cmdline| {
+            cmdline.split(|b| *b == 0)
+                .filter(|arg| !arg.is_empty())
+                .map(|arg| String::from_utf8_lossy(arg).to_string())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
 fn get_process_name(pid: u32) -> Option<String> {
     let comm = fs::read_to_string(format!("/proc/{}/comm", pid)).ok()?;
     Some(comm.trim().to_string())
@@ -137,13 +673,95 @@ fn get_process_name(pid: u32) -> Option<String> {
 
 impl ShellInfo {
     pub fn display(&self) -> String {
-        match &self.version {
+        let running = match &self.version {
             Some(v) => format!("{} {}", self.name, v),
             None => self.name.clone(),
+        };
+        
+        if self.login_shell != self.name {
+            format!("{} (login: {})", running, self.login_shell)
+        } else {
+            running
         }
     }
     
     pub fn display_terminal(&self) -> String {
-        self.terminal.clone()
+        let mut result = match &self.terminal_version {
+            Some(v) => format!("{} {}", self.terminal, v),
+            None => self.terminal.clone(),
+        };
+        
+        if let Some(mux) = &self.multiplexer {
+            result.push_str(&format!(" ({})", mux));
+        }
+        
+        if self.over_ssh && self.terminal != "SSH" {
+            result.push_str(" via SSH");
+        }
+        
+        result
+    }
+    
+    pub fn fields(&self) -> Fields {
+        vec![
+            ("name", self.name.as_str().into()),
+            ("version", self.version.clone().into()),
+            ("path", self.path.as_str().into()),
+            ("login_shell", self.login_shell.as_str().into()),
+            ("login_path", self.login_path.as_str().into()),
+        ]
+    }
+    
+    pub fn terminal_fields(&self) -> Fields {
+        vec![
+            ("name", self.terminal.as_str().into()),
+            ("version", self.terminal_version.clone().into()),
+            ("font", self.terminal_font.clone().into()),
+            ("multiplexer", self.multiplexer.clone().into()),
+            ("ssh", self.over_ssh.into()),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn scan(padding: usize, content: &[u8]) -> Option<String> {
+        let path = env::temp_dir().join(format!("hyperfetch-shell-{}-{}", std::process::id(), padding));
+        let mut binary = vec![0u8; padding];
+        binary.extend_from_slice(content);
+        fs::write(&path, binary).unwrap();
+        let version = version_from_binary("bash", &path.to_string_lossy());
+        let _ = fs::remove_file(&path);
+        version
+    }
+    
+    #[test]
+    fn finds_version_marker_across_chunks() {
+        let marker = b"\0@(#)Bash version 5.2.15(1)-release\0";
+        assert_eq!(scan(10, marker), Some("5.2.15".to_string()));
+        assert_eq!(scan(SCAN_CHUNK_SIZE - 8, marker), Some("5.2.15".to_string()));
+        assert_eq!(scan(SCAN_CHUNK_SIZE * 2 - 25, marker), Some("5.2.15".to_string()));
+    }
+    
+    #[test]
+    fn skips_implausible_versions() {
+        assert_eq!(scan(0, b"@(#)Bash version %s\0@(#)Bash version 4.4\0"), Some("4.4".to_string()));
+        assert_eq!(scan(0, b"no version here"), None);
+    }
+    
+    #[test]
+    fn ignores_relative_paths() {
+        assert_eq!(version_from_binary("bash", "bash"), None);
+    }
+    
+    #[test]
+    fn tmux_socket_from_client_cmdline() {
+        let cmdline = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+        assert_eq!(tmux_socket_path(&cmdline(&["tmux", "-S", "/run/tmux.sock", "attach"]), 1000), "/run/tmux.sock");
+        assert_eq!(tmux_socket_path(&cmdline(&["tmux", "-S/run/tmux.sock"]), 1000), "/run/tmux.sock");
+        assert!(tmux_socket_path(&cmdline(&["tmux", "-L", "work", "new"]), 1000).ends_with("/tmux-1000/work"));
+        assert!(tmux_socket_path(&cmdline(&["tmux", "attach", "-t", "main"]), 1000).ends_with("/tmux-1000/default"));
     }
 }
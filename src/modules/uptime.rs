@@ -15,7 +15,12 @@
 * along with this program. If not, see <https://www.gnu.org/licenses/>
 */
 
+use std::collections::HashSet;
+use std::env;
+use std::ffi::CStr;
 use std::fs;
+use chrono::{Local, TimeZone};
+use super::Fields;
 
 #[derive(Debug, Clone)]
 pub struct UptimeInfo {
@@ -24,6 +29,18 @@ pub struct UptimeInfo {
     pub hours: u64,
     pub minutes: u64,
     pub seconds: u64,
+    pub boot_time: Option<i64>,
+    pub session_login_time: Option<i64>,
+    pub logged_in_users: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, clap::ValueEnum)]
+pub enum UptimeFormat {
+    #[value(alias = "display")]
+    Long,
+    Short,
+    #[value(alias = "timestamp")]
+    Iso,
 }
 
 pub fn get_info() -> UptimeInfo {
@@ -34,12 +51,17 @@ pub fn get_info() -> UptimeInfo {
     let minutes = (uptime_secs % 3600) / 60;
     let seconds = uptime_secs % 60;
     
+    let sessions = read_login_sessions();
+    
     UptimeInfo {
         total_seconds: uptime_secs,
         days,
         hours,
         minutes,
         seconds,
+        boot_time: read_boot_time(),
+        session_login_time: sessions.current_login,
+        logged_in_users: sessions.users.len(),
     }
 }
 
@@ -50,6 +72,126 @@ fn read_uptime() -> Option<u64> {
     Some(uptime_float as u64)
 }
 
+fn read_boot_time() -> Option<i64> {
+    let content = fs::read_to_string("/proc/stat").ok()?;
+    content.lines()
+        .find_map(|line| line.strip_prefix("btime "))
+        .and_then(|value| value.trim().parse().ok())
+}
+
+struct LoginSessions {
+    users: HashSet<String>,
+    current_login: Option<i64>,
+}
+
+fn read_login_sessions() -> LoginSessions {
+    let mut sessions = LoginSessions {
+        users: HashSet::new(),
+        current_login: None,
+    };
+    
+    let current_session = env::var("XDG_SESSION_ID").ok().or_else(|| {
+        fs::read_to_string("/proc/self/sessionid")
+            .ok()
+            .map(|s| s.trim().to_string())
+            .filter(|s| s != "4294967295")
+    });
+    
+    if let Ok(entries) = fs::read_dir("/run/systemd/sessions") {
+        for entry in entries.flatten() {
+            let id = entry.file_name().to_string_lossy().to_string();
+            if id.ends_with(".ref") {
+                continue;
+            }
+            
+            let Ok(content) = fs::read_to_string(entry.path()) else {
+                continue;
+            };
+            
+            let mut name = None;
+            let mut class = None;
+            let mut realtime = None;
+            for line in content.lines() {
+                if let Some(value) = line.strip_prefix("NAME=") {
+                    name = Some(value.to_string());
+                } else if let Some(value) = line.strip_prefix("CLASS=") {
+                    class = Some(value.to_string());
+                } else if let Some(value) = line.strip_prefix("REALTIME=") {
+                    realtime = value.parse::<i64>().ok();
+                }
+            }
+            
+            if class.as_deref().is_some_and(|c| c != "user") {
+                continue;
+            }
+            if let Some(name) = name {
+                sessions.users.insert(name);
+            }
+            if current_session.as_deref() == Some(id.as_str()) {
+                sessions.current_login = realtime.map(|usec| usec / 1_000_000);
+            }
+        }
+    }
+    
+    let tty = fs::read_link("/proc/self/fd/0")
+        .ok()
+        .and_then(|p| p.to_string_lossy().strip_prefix("/dev/").map(|s| s.to_string()));
+    
+    for entry in read_utmp_user_processes() {
+        if sessions.current_login.is_none() && tty.as_deref() == Some(entry.line.as_str()) {
+            sessions.current_login = Some(entry.login_time);
+        }
+        sessions.users.insert(entry.user);
+    }
+    
+    sessions
+}
+
+struct UtmpEntry {
+    user: String,
+    line: String,
+    login_time: i64,
+}
+
+fn read_utmp_user_processes() -> Vec<UtmpEntry> {
+    let mut entries = Vec::new();
+    
+    unsafe {
+        libc::setutxent();
+        loop {
+            let record = libc::getutxent();
+            if record.is_null() {
+                break;
+            }
+            let record = &*record;
+            if record.ut_type != libc::USER_PROCESS {
+                continue;
+            }
+            
+            let user = CStr::from_ptr(record.ut_user.as_ptr()).to_string_lossy().to_string();
+            let line = CStr::from_ptr(record.ut_line.as_ptr()).to_string_lossy().to_string();
+            if user.is_empty() {
+                continue;
+            }
+            
+            entries.push(UtmpEntry {
+                user,
+                line,
+                login_time: record.ut_tv.tv_sec as i64,
+            });
+        }
+        libc::endutxent();
+    }
+    
+    entries
+}
+
+fn format_timestamp(timestamp: i64, pattern: &str) -> Option<String> {
+    Local.timestamp_opt(timestamp, 0)
+        .single()
+        .map(|time| time.format(pattern).to_string())
+}
+
 impl UptimeInfo {
     pub fn display(&self) -> String {
         let mut parts = Vec::new();
@@ -79,4 +221,53 @@ impl UptimeInfo {
             format!("{}m", self.minutes)
         }
     }
+    
+    pub fn display_iso(&self) -> Option<String> {
+        format_timestamp(self.boot_time?, "%Y-%m-%dT%H:%M:%S%:z")
+    }
+    
+    pub fn display_as(&self, format: UptimeFormat) -> String {
+        match format {
+            UptimeFormat::Long => self.display(),
+            UptimeFormat::Short => self.display_short(),
+            UptimeFormat::Iso => self.display_iso().unwrap_or_else(|| self.display()),
+        }
+    }
+    
+    pub fn display_boot(&self) -> Option<String> {
+        format_timestamp(self.boot_time?, "%Y-%m-%d %H:%M")
+    }
+    
+    pub fn display_session(&self) -> Option<String> {
+        let login = self.session_login_time
+            .and_then(|time| format_timestamp(time, "%Y-%m-%d %H:%M"));
+        let users = match self.logged_in_users {
+            0 => None,
+            1 => Some("1 user".to_string()),
+            n => Some(format!("{} users", n)),
+        };
+        
+        match (login, users) {
+            (Some(login), Some(users)) => Some(format!("since {}, {}", login, users)),
+            (Some(login), None) => Some(format!("since {}", login)),
+            (None, Some(users)) => Some(users),
+            (None, None) => None,
+        }
+    }
+    
+    pub fn fields(&self) -> Fields {
+        vec![
+            ("long", self.display().into()),
+            ("short", self.display_short().into()),
+            ("total_seconds", self.total_seconds.into()),
+            ("days", self.days.into()),
+            ("hours", self.hours.into()),
+            ("minutes", self.minutes.into()),
+            ("seconds", self.seconds.into()),
+            ("boot_time", self.boot_time.into()),
+            ("boot_iso", self.display_iso().into()),
+            ("session_login", self.session_login_time.into()),
+            ("users", self.logged_in_users.into()),
+        ]
+    }
 }
@@ -16,6 +16,7 @@
 */
 
 use std::env;
+use super::Fields;
 
 #[derive(Debug, Clone)]
 pub struct UserInfo {
@@ -42,4 +43,12 @@ impl UserInfo {
     pub fn display(&self) -> String {
         format!("{}@{}", self.username, self.hostname)
     }
+    
+    pub fn fields(&self) -> Fields {
+        vec![
+            ("username", self.username.as_str().into()),
+            ("hostname", self.hostname.as_str().into()),
+            ("home", self.home_dir.as_str().into()),
+        ]
+    }
 }
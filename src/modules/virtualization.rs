@@ -0,0 +1,315 @@
+/*
+* Copyright (C) 2026 compiledkernel-idk <https://github.com/compiledkernel-idk>
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program. If not, see <https://www.gnu.org/licenses/>
+*/
+
+use std::env;
+use std::fs;
+use std::path::Path;
+use super::Fields;
+
+#[derive(Debug, Clone)]
+pub struct VirtualizationInfo {
+    pub hypervisor: Option<String>,
+    pub container: Option<String>,
+    pub sandbox: Option<String>,
+}
+
+pub fn get_info() -> VirtualizationInfo {
+    VirtualizationInfo {
+        hypervisor: detect_hypervisor(),
+        container: detect_container(),
+        sandbox: detect_sandbox(),
+    }
+}
+
+fn read_trimmed(path: &str) -> Option<String> {
+    fs::read_to_string(path)
+        .ok()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
+fn detect_hypervisor() -> Option<String> {
+    if let Some(name) = hypervisor_from_dmi() {
+        return Some(name);
+    }
+    
+    if let Some(xen) = read_trimmed("/sys/hypervisor/type") {
+        if xen == "xen" {
+            return Some("Xen".to_string());
+        }
+    }
+    
+    let signature = hypervisor_from_cpuid();
+    
+    if let Some(name) = detect_microvm(signature.as_deref()) {
+        return Some(name);
+    }
+    
+    if signature.is_some() {
+        return signature;
+    }
+    
+    let cpuinfo = fs::read_to_string("/proc/cpuinfo").unwrap_or_default();
+    let has_flag = cpuinfo.lines()
+        .filter(|line| line.starts_with("flags"))
+        .any(|line| line.split_whitespace().any(|flag| flag == "hypervisor"));
+    
+    if has_flag {
+        Some("Unknown hypervisor".to_string())
+    } else {
+        None
+    }
+}
+
+fn hypervisor_from_dmi() -> Option<String> {
+    let fields = [
+        "/sys/class/dmi/id/sys_vendor",
+        "/sys/class/dmi/id/product_name",
+        "/sys/class/dmi/id/board_vendor",
+        "/sys/class/dmi/id/bios_vendor",
+    ];
+    
+    let values: Vec<String> = fields.iter()
+        .filter_map(|path| read_trimmed(path))
+        .collect();
+    
+    let checks = [
+        ("qemu", "QEMU"),
+        ("kvm", "KVM"),
+        ("vmware", "VMware"),
+        ("innotek", "VirtualBox"),
+        ("virtualbox", "VirtualBox"),
+        ("xen", "Xen"),
+        ("bochs", "Bochs"),
+        ("parallels", "Parallels"),
+        ("bhyve", "bhyve"),
+        ("amazon ec2", "Amazon EC2"),
+        ("google compute engine", "Google Compute Engine"),
+        ("openstack", "OpenStack"),
+        ("cloud hypervisor", "Cloud Hypervisor"),
+    ];
+    
+    for value in &values {
+        let lower = value.to_lowercase();
+        for (needle, name) in &checks {
+            if lower.contains(needle) {
+                return Some(name.to_string());
+            }
+        }
+    }
+    
+    let vendor = values.first()?.to_lowercase();
+    let product = read_trimmed("/sys/class/dmi/id/product_name").unwrap_or_default();
+    if vendor.contains("microsoft") && product == "Virtual Machine" {
+        return Some("Hyper-V".to_string());
+    }
+    
+    None
+}
+
+#[cfg(target_arch = "x86_64")]
+#[allow(unused_unsafe)]
+fn hypervisor_from_cpuid() -> Option<String> {
+    use std::arch::x86_64::__cpuid;
+    
+    let features = unsafe { __cpuid(1) };
+    if (features.ecx >> 31) & 1 == 0 {
+        return None;
+    }
+    
+    let leaf = unsafe { __cpuid(0x4000_0000) };
+    let mut bytes = Vec::with_capacity(12);
+    for reg in [leaf.ebx, leaf.ecx, leaf.edx] {
+        bytes.extend_from_slice(&reg.to_le_bytes());
+    }
+    let signature = String::from_utf8_lossy(&bytes).trim_matches('\0').trim().to_string();
+    
+    let name = match signature.as_str() {
+        "KVMKVMKVM" => "KVM",
+        "Microsoft Hv" => "Hyper-V",
+        "VMwareVMware" => "VMware",
+        "XenVMMXenVMM" => "Xen",
+        "VBoxVBoxVBox" => "VirtualBox",
+        "TCGTCGTCGTCG" => "QEMU",
+        "bhyve bhyve" => "bhyve",
+        "lrpepyh  vr" => "Parallels",
+        "ACRNACRNACRN" => "ACRN",
+        "" => return None,
+        other => other,
+    };
+    
+    Some(name.to_string())
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+fn hypervisor_from_cpuid() -> Option<String> {
+    None
+}
+
+fn detect_microvm(signature: Option<&str>) -> Option<String> {
+    if Path::new("/sys/class/dmi/id").exists() {
+        return None;
+    }
+    
+    let devices: Vec<String> = fs::read_dir("/sys/bus/platform/devices").ok()?
+        .flatten()
+        .map(|entry| entry.file_name().to_string_lossy().to_string())
+        .collect();
+    
+    if devices.iter().any(|name| name.starts_with("AMZNC10C")) || acpi_oem_id().as_deref() == Some("FIRECK") {
+        return Some("Firecracker".to_string());
+    }
+    
+    let virtio_mmio = devices.iter().any(|name| name.starts_with("virtio") || name.starts_with("LNRO0005"));
+    if virtio_mmio {
+        Some(format!("{} (microVM)", signature.unwrap_or("KVM")))
+    } else {
+        None
+    }
+}
+
+fn acpi_oem_id() -> Option<String> {
+    let mut header = [0u8; 16];
+    let mut table = fs::File::open("/sys/firmware/acpi/tables/DSDT").ok()?;
+    std::io::Read::read_exact(&mut table, &mut header).ok()?;
+    Some(String::from_utf8_lossy(&header[10..16]).trim().to_string())
+}
+
+fn detect_container() -> Option<String> {
+    let kubernetes = env::var("KUBERNETES_SERVICE_HOST").is_ok();
+    
+    let runtime = container_runtime();
+    
+    match (kubernetes, runtime) {
+        (true, Some(runtime)) => Some(format!("Kubernetes ({})", runtime)),
+        (true, None) => Some("Kubernetes".to_string()),
+        (false, runtime) => runtime,
+    }
+}
+
+fn container_runtime() -> Option<String> {
+    if let Some(engine) = read_containerenv_engine() {
+        return Some(engine);
+    }
+    
+    if Path::new("/.dockerenv").exists() {
+        return Some("Docker".to_string());
+    }
+    
+    let declared = read_trimmed("/run/systemd/container").or_else(|| {
+        let environ = fs::read("/proc/1/environ").ok()?;
+        environ.split(|b| *b == 0)
+            .filter_map(|entry| std::str::from_utf8(entry).ok())
+            .find_map(|entry| entry.strip_prefix("container="))
+            .map(|value| value.to_string())
+    });
+    
+    if let Some(value) = declared {
+        return Some(normalize_container_name(&value));
+    }
+    
+    let cgroup = fs::read_to_string("/proc/self/cgroup").unwrap_or_default();
+    let cgroup_checks = [
+        ("kubepods", "Kubernetes"),
+        ("libpod", "Podman"),
+        ("docker", "Docker"),
+        ("containerd", "containerd"),
+        ("lxc", "LXC"),
+        ("machine.slice/machine-", "systemd-nspawn"),
+    ];
+    
+    for (needle, name) in &cgroup_checks {
+        if cgroup.contains(needle) {
+            return Some(name.to_string());
+        }
+    }
+    
+    None
+}
+
+fn read_containerenv_engine() -> Option<String> {
+    let content = fs::read_to_string("/run/.containerenv").ok()?;
+    let engine = content.lines()
+        .find_map(|line| line.strip_prefix("engine="))
+        .map(|value| value.trim_matches('"').to_string())
+        .unwrap_or_default();
+    
+    if engine.starts_with("podman") || engine.is_empty() {
+        Some("Podman".to_string())
+    } else {
+        Some(normalize_container_name(&engine))
+    }
+}
+
+fn normalize_container_name(value: &str) -> String {
+    match value {
+        "docker" => "Docker".to_string(),
+        "podman" => "Podman".to_string(),
+        "lxc" | "lxc-libvirt" => "LXC".to_string(),
+        "systemd-nspawn" => "systemd-nspawn".to_string(),
+        "oci" => "OCI container".to_string(),
+        "wsl" => "WSL".to_string(),
+        other => other.to_string(),
+    }
+}
+
+fn detect_sandbox() -> Option<String> {
+    if Path::new("/.flatpak-info").exists() || env::var("FLATPAK_ID").is_ok() {
+        return Some("Flatpak".to_string());
+    }
+    
+    if env::var("SNAP").is_ok() && env::var("SNAP_NAME").is_ok() {
+        return Some("Snap".to_string());
+    }
+    
+    None
+}
+
+impl VirtualizationInfo {
+    pub fn is_detected(&self) -> bool {
+        self.hypervisor.is_some() || self.container.is_some() || self.sandbox.is_some()
+    }
+    
+    pub fn display(&self) -> String {
+        let mut parts = Vec::new();
+        
+        if let Some(sandbox) = &self.sandbox {
+            parts.push(format!("{} sandbox", sandbox));
+        }
+        if let Some(container) = &self.container {
+            parts.push(format!("{} container", container));
+        }
+        if let Some(hypervisor) = &self.hypervisor {
+            parts.push(format!("{} VM", hypervisor));
+        }
+        
+        if parts.is_empty() {
+            "Bare metal".to_string()
+        } else {
+            parts.join(" in ")
+        }
+    }
+    
+    pub fn fields(&self) -> Fields {
+        vec![
+            ("hypervisor", self.hypervisor.clone().into()),
+            ("container", self.container.clone().into()),
+            ("sandbox", self.sandbox.clone().into()),
+            ("detected", self.is_detected().into()),
+        ]
+    }
+}
@@ -16,8 +16,32 @@
 */
 
 use colored::*;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use crate::config;
+use super::terminal::{self, contrast_ratio, Rgb, TerminalPalette};
+
+pub const BUILTIN_THEMES: &[&str] = &[
+    "auto",
+    "default",
+    "dracula",
+    "nord",
+    "gruvbox",
+    "catppuccin",
+    "monokai",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ColorDepth {
+    Basic,
+    Ansi256,
+    TrueColor,
+}
 
 pub struct Theme {
+    pub name: String,
+    pub depth: ColorDepth,
     pub label_color: Color,
     pub value_color: Color,
     pub title_color: Color,
@@ -29,19 +53,40 @@ pub struct Theme {
 
 impl Theme {
     pub fn apply_label(&self, text: &str) -> String {
-        text.color(self.label_color).bold().to_string()
+        self.paint_styled(text, self.label_color, true)
     }
     
     pub fn apply_value(&self, text: &str) -> String {
-        text.color(self.value_color).to_string()
+        self.paint(text, self.value_color)
     }
     
     pub fn apply_title(&self, text: &str) -> String {
-        text.color(self.title_color).bold().to_string()
+        self.paint_styled(text, self.title_color, true)
     }
     
     pub fn apply_separator(&self, text: &str) -> String {
-        text.color(self.separator_color).to_string()
+        self.paint(text, self.separator_color)
+    }
+    
+    pub fn paint(&self, text: &str, color: Color) -> String {
+        self.paint_styled(text, color, false)
+    }
+    
+    fn paint_styled(&self, text: &str, color: Color, bold: bool) -> String {
+        if !control::SHOULD_COLORIZE.should_colorize() {
+            return text.to_string();
+        }
+        
+        let code = match color {
+            Color::TrueColor { r, g, b } => rgb_code((r, g, b), self.depth, false),
+            color => color.to_fg_str().to_string(),
+        };
+        
+        if bold {
+            format!("\x1b[1;{}m{}\x1b[0m", code, text)
+        } else {
+            format!("\x1b[{}m{}\x1b[0m", code, text)
+        }
     }
     
     pub fn apply_color_blocks(&self) -> String {
@@ -59,62 +104,451 @@ impl Theme {
     }
 }
 
-pub fn get_theme(name: &str) -> Theme {
-    match name.to_lowercase().as_str() {
-        "dracula" => Theme {
-            label_color: Color::Magenta,
-            value_color: Color::Cyan,
-            title_color: Color::BrightMagenta,
-            separator_color: Color::BrightBlack,
-            bar_good_color: Color::Green,
-            bar_warn_color: Color::Yellow,
-            bar_bad_color: Color::Red,
-        },
-        "nord" => Theme {
-            label_color: Color::Blue,
-            value_color: Color::Cyan,
-            title_color: Color::BrightBlue,
-            separator_color: Color::BrightBlack,
-            bar_good_color: Color::Green,
-            bar_warn_color: Color::Yellow,
-            bar_bad_color: Color::Red,
-        },
-        "gruvbox" => Theme {
-            label_color: Color::Yellow,
-            value_color: Color::BrightYellow,
-            title_color: Color::BrightRed,
-            separator_color: Color::BrightBlack,
-            bar_good_color: Color::Green,
-            bar_warn_color: Color::Yellow,
-            bar_bad_color: Color::Red,
-        },
-        "catppuccin" => Theme {
-            label_color: Color::Magenta,
-            value_color: Color::Blue,
-            title_color: Color::BrightMagenta,
-            separator_color: Color::BrightBlack,
-            bar_good_color: Color::Green,
-            bar_warn_color: Color::Yellow,
-            bar_bad_color: Color::Red,
-        },
-        "monokai" => Theme {
-            label_color: Color::BrightMagenta,
-            value_color: Color::BrightGreen,
-            title_color: Color::BrightYellow,
-            separator_color: Color::BrightBlack,
-            bar_good_color: Color::Green,
-            bar_warn_color: Color::Yellow,
-            bar_bad_color: Color::Red,
-        },
-        _ => Theme {
-            
-            label_color: Color::BrightBlue,
-            value_color: Color::White,
-            title_color: Color::BrightCyan,
-            separator_color: Color::BrightBlack,
-            bar_good_color: Color::Green,
-            bar_warn_color: Color::Yellow,
-            bar_bad_color: Color::Red,
-        },
+pub fn rgb_code(rgb: Rgb, depth: ColorDepth, background: bool) -> String {
+    let (r, g, b) = rgb;
+    let layer = if background { 48 } else { 38 };
+    
+    match depth {
+        ColorDepth::TrueColor => format!("{};2;{};{};{}", layer, r, g, b),
+        ColorDepth::Ansi256 => format!("{};5;{}", layer, rgb_to_ansi256(r, g, b)),
+        ColorDepth::Basic if background => nearest_basic(r, g, b).to_bg_str().to_string(),
+        ColorDepth::Basic => nearest_basic(r, g, b).to_fg_str().to_string(),
+    }
+}
+
+pub fn detect_color_depth() -> ColorDepth {
+    if let Ok(colorterm) = env::var("COLORTERM") {
+        if colorterm == "truecolor" || colorterm == "24bit" {
+            return ColorDepth::TrueColor;
+        }
+    }
+    
+    let term = env::var("TERM").unwrap_or_default();
+    if let Some(colors) = terminfo_max_colors(&term) {
+        return if colors >= 1 << 24 {
+            ColorDepth::TrueColor
+        } else if colors >= 256 {
+            ColorDepth::Ansi256
+        } else {
+            ColorDepth::Basic
+        };
+    }
+    
+    if term.ends_with("-direct") {
+        ColorDepth::TrueColor
+    } else if term.contains("256color") {
+        ColorDepth::Ansi256
+    } else {
+        ColorDepth::Basic
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, clap::ValueEnum)]
+pub enum ColorMode {
+    #[default]
+    Auto,
+    #[value(alias = "force")]
+    Always,
+    #[value(alias = "none", alias = "off")]
+    Never,
+}
+
+fn env_enabled(name: &str) -> Option<bool> {
+    env::var(name).ok().map(|value| !value.is_empty() && value != "0")
+}
+
+pub fn should_colorize(mode: ColorMode, pipe: bool) -> bool {
+    match mode {
+        ColorMode::Always => return true,
+        ColorMode::Never => return false,
+        ColorMode::Auto => {}
+    }
+    if pipe {
+        return false;
+    }
+    if env_enabled("CLICOLOR_FORCE") == Some(true) {
+        return true;
+    }
+    if env::var("NO_COLOR").is_ok_and(|value| !value.is_empty()) || env_enabled("CLICOLOR") == Some(false) {
+        return false;
+    }
+    terminal::stdout_is_tty()
+}
+
+fn terminfo_dirs() -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    
+    if let Ok(dir) = env::var("TERMINFO") {
+        dirs.push(PathBuf::from(dir));
+    }
+    if let Ok(home) = env::var("HOME") {
+        dirs.push(PathBuf::from(home).join(".terminfo"));
+    }
+    if let Ok(list) = env::var("TERMINFO_DIRS") {
+        dirs.extend(list.split(':').filter(|d| !d.is_empty()).map(PathBuf::from));
+    }
+    for dir in ["/etc/terminfo", "/lib/terminfo", "/usr/share/terminfo", "/usr/lib/terminfo"] {
+        dirs.push(PathBuf::from(dir));
+    }
+    
+    dirs
+}
+
+fn terminfo_max_colors(term: &str) -> Option<i64> {
+    let first = term.chars().next()?;
+    let data = terminfo_dirs().into_iter()
+        .flat_map(|dir| [
+            dir.join(first.to_string()).join(term),
+            dir.join(format!("{:x}", first as u32)).join(term),
+        ])
+        .find_map(|path| fs::read(path).ok())?;
+    
+    let read_i16 = |offset: usize| -> Option<i64> {
+        let bytes = data.get(offset..offset + 2)?;
+        Some(i16::from_le_bytes([bytes[0], bytes[1]]) as i64)
+    };
+    
+    let number_size = match read_i16(0)? {
+        0o432 => 2,
+        0o1036 => 4,
+        _ => return None,
+    };
+    let names_size = read_i16(2)? as usize;
+    let bools_count = read_i16(4)? as usize;
+    let numbers_count = read_i16(6)? as usize;
+    
+    const MAX_COLORS: usize = 13;
+    if numbers_count <= MAX_COLORS {
+        return None;
+    }
+    
+    let mut offset = 12 + names_size + bools_count;
+    if offset % 2 == 1 {
+        offset += 1;
+    }
+    offset += MAX_COLORS * number_size;
+    
+    let bytes = data.get(offset..offset + number_size)?;
+    let value = if number_size == 2 {
+        i16::from_le_bytes([bytes[0], bytes[1]]) as i64
+    } else {
+        i32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as i64
+    };
+    
+    if value < 0 { None } else { Some(value) }
+}
+
+fn rgb_to_ansi256(r: u8, g: u8, b: u8) -> u8 {
+    const LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+    let cube_index = |v: u8| -> usize {
+        LEVELS.iter()
+            .enumerate()
+            .min_by_key(|(_, level)| (**level as i32 - v as i32).abs())
+            .map(|(i, _)| i)
+            .unwrap_or(0)
+    };
+    
+    let (ri, gi, bi) = (cube_index(r), cube_index(g), cube_index(b));
+    let cube = (LEVELS[ri], LEVELS[gi], LEVELS[bi]);
+    
+    let average = (r as u32 + g as u32 + b as u32) / 3;
+    let gray_index = if average < 8 { 0 } else { ((average - 8) / 10).min(23) };
+    let gray = (8 + gray_index * 10) as u8;
+    
+    if color_distance((r, g, b), (gray, gray, gray)) < color_distance((r, g, b), cube) {
+        232 + gray_index as u8
+    } else {
+        16 + (36 * ri + 6 * gi + bi) as u8
+    }
+}
+
+fn color_distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let dr = a.0 as i32 - b.0 as i32;
+    let dg = a.1 as i32 - b.1 as i32;
+    let db = a.2 as i32 - b.2 as i32;
+    (dr * dr + dg * dg + db * db) as u32
+}
+
+const BASIC_PALETTE: &[(Color, (u8, u8, u8))] = &[
+    (Color::Black, (0, 0, 0)),
+    (Color::Red, (205, 0, 0)),
+    (Color::Green, (0, 205, 0)),
+    (Color::Yellow, (205, 205, 0)),
+    (Color::Blue, (0, 0, 238)),
+    (Color::Magenta, (205, 0, 205)),
+    (Color::Cyan, (0, 205, 205)),
+    (Color::White, (229, 229, 229)),
+    (Color::BrightBlack, (127, 127, 127)),
+    (Color::BrightRed, (255, 0, 0)),
+    (Color::BrightGreen, (0, 255, 0)),
+    (Color::BrightYellow, (255, 255, 0)),
+    (Color::BrightBlue, (92, 92, 255)),
+    (Color::BrightMagenta, (255, 0, 255)),
+    (Color::BrightCyan, (0, 255, 255)),
+    (Color::BrightWhite, (255, 255, 255)),
+];
+
+fn basic_index(color: Color) -> Option<u8> {
+    BASIC_PALETTE.iter().position(|(c, _)| *c == color).map(|i| i as u8)
+}
+
+fn nearest_basic(r: u8, g: u8, b: u8) -> Color {
+    BASIC_PALETTE.iter()
+        .min_by_key(|(_, rgb)| color_distance((r, g, b), *rgb))
+        .map(|(color, _)| *color)
+        .unwrap_or(Color::White)
+}
+
+pub fn parse_color(value: &str) -> Option<Color> {
+    let value = value.trim();
+    
+    if let Some(hex) = value.strip_prefix('#') {
+        if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        let hex = match hex.len() {
+            3 => hex.chars().flat_map(|c| [c, c]).collect(),
+            6 => hex.to_string(),
+            _ => return None,
+        };
+        let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+        return Some(Color::TrueColor {
+            r: channel(0)?,
+            g: channel(2)?,
+            b: channel(4)?,
+        });
+    }
+    
+    let name = value.to_lowercase().replace(['-', '_', ' '], "");
+    BASIC_PALETTE.iter()
+        .find(|(color, _)| format!("{:?}", color).to_lowercase() == name)
+        .map(|(color, _)| *color)
+}
+
+fn rgb(hex: u32) -> Color {
+    Color::TrueColor {
+        r: (hex >> 16) as u8,
+        g: (hex >> 8) as u8,
+        b: hex as u8,
+    }
+}
+
+fn builtin_theme(name: &str, depth: ColorDepth) -> Theme {
+    let (name, colors) = match name.to_lowercase().as_str() {
+        "dracula" => ("dracula", [
+            rgb(0xbd93f9), rgb(0xf8f8f2), rgb(0xff79c6), rgb(0x6272a4),
+            rgb(0x50fa7b), rgb(0xf1fa8c), rgb(0xff5555),
+        ]),
+        "nord" => ("nord", [
+            rgb(0x81a1c1), rgb(0xd8dee9), rgb(0x88c0d0), rgb(0x4c566a),
+            rgb(0xa3be8c), rgb(0xebcb8b), rgb(0xbf616a),
+        ]),
+        "gruvbox" => ("gruvbox", [
+            rgb(0xfabd2f), rgb(0xebdbb2), rgb(0xfb4934), rgb(0x928374),
+            rgb(0xb8bb26), rgb(0xfe8019), rgb(0xcc241d),
+        ]),
+        "catppuccin" => ("catppuccin", [
+            rgb(0xcba6f7), rgb(0xcdd6f4), rgb(0xf5c2e7), rgb(0x6c7086),
+            rgb(0xa6e3a1), rgb(0xf9e2af), rgb(0xf38ba8),
+        ]),
+        "monokai" => ("monokai", [
+            rgb(0xf92672), rgb(0xa6e22e), rgb(0xe6db74), rgb(0x75715e),
+            rgb(0xa6e22e), rgb(0xfd971f), rgb(0xf92672),
+        ]),
+        _ => ("default", [
+            Color::BrightBlue, Color::White, Color::BrightCyan, Color::BrightBlack,
+            Color::Green, Color::Yellow, Color::Red,
+        ]),
+    };
+    
+    let [label, value, title, separator, good, warn, bad] = colors;
+    Theme {
+        name: name.to_string(),
+        depth,
+        label_color: label,
+        value_color: value,
+        title_color: title,
+        separator_color: separator,
+        bar_good_color: good,
+        bar_warn_color: warn,
+        bar_bad_color: bad,
+    }
+}
+
+pub fn themes_dir() -> PathBuf {
+    config::config_dir().join("themes")
+}
+
+fn load_user_theme(name: &str, depth: ColorDepth, nesting: usize) -> Option<Theme> {
+    let path = themes_dir().join(format!("{}.toml", name));
+    let table = config::read_toml(&path)?;
+    
+    let mut values = table.get("").cloned().unwrap_or_default();
+    if let Some(colors) = table.get("colors") {
+        values.extend(colors.clone());
+    }
+    
+    let mut theme = match values.get("inherits") {
+        Some(parent) if nesting < 8 && parent != name => load_user_theme(parent, depth, nesting + 1)
+            .unwrap_or_else(|| builtin_theme(parent, depth)),
+        _ => builtin_theme("default", depth),
+    };
+    theme.name = name.to_string();
+    
+    let color = |key: &str| {
+        values.get(key)
+            .or_else(|| values.get(&format!("{}_color", key)))
+            .and_then(|value| parse_color(value))
+    };
+    
+    if let Some(c) = color("label") { theme.label_color = c; }
+    if let Some(c) = color("value") { theme.value_color = c; }
+    if let Some(c) = color("title") { theme.title_color = c; }
+    if let Some(c) = color("separator") { theme.separator_color = c; }
+    if let Some(c) = color("bar_good") { theme.bar_good_color = c; }
+    if let Some(c) = color("bar_warn") { theme.bar_warn_color = c; }
+    if let Some(c) = color("bar_bad") { theme.bar_bad_color = c; }
+    
+    Some(theme)
+}
+
+pub fn theme_names() -> Vec<String> {
+    let mut names: Vec<String> = BUILTIN_THEMES.iter().map(|s| s.to_string()).collect();
+    
+    if let Ok(entries) = fs::read_dir(themes_dir()) {
+        let mut user: Vec<String> = entries.flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().map(|e| e == "toml").unwrap_or(false))
+            .filter_map(|path| path.file_stem().map(|s| s.to_string_lossy().to_string()))
+            .filter(|name| !names.contains(name))
+            .collect();
+        user.sort();
+        names.extend(user);
+    }
+    
+    names
+}
+
+pub fn get_theme(name: &str) -> Option<Theme> {
+    let depth = detect_color_depth();
+    load_user_theme(name, depth, 0).or_else(|| {
+        BUILTIN_THEMES.iter()
+            .any(|builtin| builtin.eq_ignore_ascii_case(name))
+            .then(|| builtin_theme(name, depth))
+    })
+}
+
+fn actual_rgb(color: Color, palette: &TerminalPalette) -> Rgb {
+    match color {
+        Color::TrueColor { r, g, b } => (r, g, b),
+        color => basic_index(color)
+            .and_then(|i| palette.colors.get(&i).copied().or_else(|| BASIC_PALETTE.get(i as usize).map(|(_, rgb)| *rgb)))
+            .unwrap_or((229, 229, 229)),
+    }
+}
+
+fn bright_variant(color: Color) -> Color {
+    match color {
+        Color::Black => Color::BrightBlack,
+        Color::Red => Color::BrightRed,
+        Color::Green => Color::BrightGreen,
+        Color::Yellow => Color::BrightYellow,
+        Color::Blue => Color::BrightBlue,
+        Color::Magenta => Color::BrightMagenta,
+        Color::Cyan => Color::BrightCyan,
+        Color::White => Color::BrightWhite,
+        Color::BrightBlack => Color::Black,
+        Color::BrightRed => Color::Red,
+        Color::BrightGreen => Color::Green,
+        Color::BrightYellow => Color::Yellow,
+        Color::BrightBlue => Color::Blue,
+        Color::BrightMagenta => Color::Magenta,
+        Color::BrightCyan => Color::Cyan,
+        Color::BrightWhite => Color::White,
+        other => other,
+    }
+}
+
+fn readable(color: Color, background: Rgb, palette: &TerminalPalette) -> Option<Color> {
+    [color, bright_variant(color)].into_iter()
+        .find(|c| contrast_ratio(actual_rgb(*c, palette), background) >= 2.5)
+}
+
+pub fn palette_query_enabled(requested: bool) -> bool {
+    control::SHOULD_COLORIZE.should_colorize()
+        && (requested || config::load().get("")
+            .and_then(|settings| settings.get("query_palette"))
+            .is_some_and(|value| value == "true"))
+}
+
+pub fn auto_theme(logo_colors: &[Color], query_palette: bool) -> Theme {
+    let depth = detect_color_depth();
+    
+    let mut indices: Vec<u8> = logo_colors.iter()
+        .flat_map(|c| [*c, bright_variant(*c)])
+        .chain([Color::Black, Color::White, Color::BrightBlack, Color::BrightWhite])
+        .filter_map(basic_index)
+        .collect();
+    indices.sort();
+    indices.dedup();
+    
+    let palette = query_palette
+        .then(|| terminal::query_palette(&indices))
+        .flatten()
+        .unwrap_or_default();
+    let background = palette.background.unwrap_or((0, 0, 0));
+    let dark = terminal::luminance(background) < 0.5;
+    
+    let mut accents: Vec<Color> = Vec::new();
+    for color in logo_colors {
+        if matches!(color, Color::White | Color::BrightWhite | Color::Black | Color::BrightBlack) {
+            continue;
+        }
+        if let Some(c) = readable(*color, background, &palette) {
+            if !accents.contains(&c) {
+                accents.push(c);
+            }
+        }
+    }
+    
+    let fallback_accent = if dark { Color::BrightBlue } else { Color::Blue };
+    let label = accents.first().copied().unwrap_or(fallback_accent);
+    let title = accents.get(1).copied().unwrap_or(label);
+    
+    let value = if dark {
+        [Color::White, Color::BrightWhite]
+    } else {
+        [Color::Black, Color::BrightBlack]
+    }
+    .into_iter()
+    .max_by(|a, b| {
+        contrast_ratio(actual_rgb(*a, &palette), background)
+            .total_cmp(&contrast_ratio(actual_rgb(*b, &palette), background))
+    })
+    .unwrap_or(Color::White);
+    
+    let (good, warn, bad) = if dark {
+        (Color::Green, Color::Yellow, Color::Red)
+    } else {
+        (Color::Green, Color::Magenta, Color::Red)
+    };
+    
+    Theme {
+        name: "auto".to_string(),
+        depth,
+        label_color: label,
+        value_color: value,
+        title_color: title,
+        separator_color: Color::BrightBlack,
+        bar_good_color: good,
+        bar_warn_color: warn,
+        bar_bad_color: bad,
+    }
+}
+
+pub fn resolve_theme(name: &str, logo_colors: &[Color], query_palette: bool) -> Result<Theme, String> {
+    if name.eq_ignore_ascii_case("auto") {
+        return Ok(auto_theme(logo_colors, query_palette));
     }
+    get_theme(name).ok_or_else(|| format!("Unknown theme '{}'. Available: {}", name, theme_names().join(", ")))
 }
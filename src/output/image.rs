@@ -0,0 +1,364 @@
+/*
+* Copyright (C) 2026 compiledkernel-idk <https://github.com/compiledkernel-idk>
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program. If not, see <https://www.gnu.org/licenses/>
+*/
+
+use std::collections::BTreeSet;
+use std::env;
+use std::fs;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use super::colors::{rgb_code, ColorDepth};
+use super::terminal;
+use super::Logo;
+
+pub const DEFAULT_COLUMNS: usize = 30;
+
+const DEFAULT_CELL_SIZE: (u32, u32) = (8, 16);
+const KITTY_CHUNK_SIZE: usize = 4096;
+const SIXEL_LEVELS: [u8; 6] = [0, 51, 102, 153, 204, 255];
+
+#[derive(Debug, Clone, Copy, PartialEq, clap::ValueEnum)]
+pub enum ImageProtocol {
+    Kitty,
+    #[value(alias = "iterm")]
+    Iterm2,
+    Sixel,
+    #[value(alias = "block", alias = "halfblock")]
+    Blocks,
+}
+
+#[derive(Debug, Clone)]
+pub struct ImageLogo {
+    pub columns: usize,
+    pub rows: usize,
+    pub sequence: String,
+}
+
+struct Pixels {
+    width: usize,
+    height: usize,
+    rgba: Vec<u8>,
+}
+
+impl Pixels {
+    fn pixel(&self, x: usize, y: usize) -> [u8; 4] {
+        let i = (y * self.width + x) * 4;
+        [self.rgba[i], self.rgba[i + 1], self.rgba[i + 2], self.rgba[i + 3]]
+    }
+}
+
+pub fn detect_protocol() -> ImageProtocol {
+    if !terminal::is_interactive() || env::var("TMUX").is_ok() {
+        return ImageProtocol::Blocks;
+    }
+    
+    let term = env::var("TERM").unwrap_or_default();
+    let program = env::var("TERM_PROGRAM").unwrap_or_default();
+    
+    if env::var("KITTY_WINDOW_ID").is_ok() || term.contains("kitty") || term.contains("ghostty") || program == "ghostty" {
+        ImageProtocol::Kitty
+    } else if program == "iTerm.app" || program == "WezTerm" || env::var("LC_TERMINAL").map(|t| t == "iTerm2").unwrap_or(false) {
+        ImageProtocol::Iterm2
+    } else if terminal::supports_sixel() {
+        ImageProtocol::Sixel
+    } else {
+        ImageProtocol::Blocks
+    }
+}
+
+pub fn load_logo(path: &str, protocol: Option<ImageProtocol>, columns: usize, depth: ColorDepth) -> Result<Logo, String> {
+    let data = fs::read(path).map_err(|e| format!("Cannot read image '{}': {}", path, e))?;
+    let image = decode_png(&data).ok_or_else(|| format!("'{}' is not a readable PNG image", path))?;
+    let columns = columns.max(1);
+    let (cell_width, cell_height) = terminal::cell_size().unwrap_or(DEFAULT_CELL_SIZE);
+    
+    let rows = ((columns * cell_width as usize * image.height) as f64
+        / (image.width * cell_height as usize) as f64)
+        .round()
+        .max(1.0) as usize;
+    
+    let sequence = match protocol.unwrap_or_else(detect_protocol) {
+        ImageProtocol::Kitty => kitty_sequence(&data, columns, rows),
+        ImageProtocol::Iterm2 => iterm2_sequence(&data, columns, rows),
+        ImageProtocol::Sixel => {
+            let scaled = resample(&image, columns * cell_width as usize, rows * cell_height as usize);
+            sixel_sequence(&scaled)
+        }
+        ImageProtocol::Blocks => {
+            let scaled = resample(&image, columns, rows * 2);
+            return Ok(Logo::Text(block_lines(&scaled, depth)));
+        }
+    };
+    
+    Ok(Logo::Image(ImageLogo { columns, rows, sequence }))
+}
+
+fn decode_png(data: &[u8]) -> Option<Pixels> {
+    let mut decoder = png::Decoder::new(data);
+    decoder.set_transformations(png::Transformations::normalize_to_color8());
+    let mut reader = decoder.read_info().ok()?;
+    let mut buffer = vec![0; reader.output_buffer_size()];
+    let frame = reader.next_frame(&mut buffer).ok()?;
+    let bytes = &buffer[..frame.buffer_size()];
+    
+    let rgba: Vec<u8> = match frame.color_type {
+        png::ColorType::Rgba => bytes.to_vec(),
+        png::ColorType::Rgb => bytes.chunks_exact(3).flat_map(|p| [p[0], p[1], p[2], 255]).collect(),
+        png::ColorType::GrayscaleAlpha => bytes.chunks_exact(2).flat_map(|p| [p[0], p[0], p[0], p[1]]).collect(),
+        png::ColorType::Grayscale => bytes.iter().flat_map(|&v| [v, v, v, 255]).collect(),
+        png::ColorType::Indexed => return None,
+    };
+    
+    Some(Pixels {
+        width: frame.width as usize,
+        height: frame.height as usize,
+        rgba,
+    })
+}
+
+fn resample(image: &Pixels, width: usize, height: usize) -> Pixels {
+    let mut rgba = Vec::with_capacity(width * height * 4);
+    
+    for y in 0..height {
+        let y0 = y * image.height / height;
+        let y1 = ((y + 1) * image.height / height).max(y0 + 1);
+        for x in 0..width {
+            let x0 = x * image.width / width;
+            let x1 = ((x + 1) * image.width / width).max(x0 + 1);
+            
+            let mut sum = [0u64; 4];
+            let mut count = 0u64;
+            for sy in y0..y1.min(image.height) {
+                for sx in x0..x1.min(image.width) {
+                    let [r, g, b, a] = image.pixel(sx, sy);
+                    let a = a as u64;
+                    sum[0] += r as u64 * a;
+                    sum[1] += g as u64 * a;
+                    sum[2] += b as u64 * a;
+                    sum[3] += a;
+                    count += 1;
+                }
+            }
+            
+            let channel = |i: usize| sum[i].checked_div(sum[3]).unwrap_or(0) as u8;
+            rgba.extend_from_slice(&[
+                channel(0),
+                channel(1),
+                channel(2),
+                (sum[3] / count.max(1)) as u8,
+            ]);
+        }
+    }
+    
+    Pixels { width, height, rgba }
+}
+
+fn kitty_sequence(data: &[u8], columns: usize, rows: usize) -> String {
+    let encoded = STANDARD.encode(data);
+    let chunks: Vec<&str> = encoded.as_bytes()
+        .chunks(KITTY_CHUNK_SIZE)
+        .map(|chunk| std::str::from_utf8(chunk).unwrap_or(""))
+        .collect();
+    
+    let mut sequence = String::new();
+    for (i, chunk) in chunks.iter().enumerate() {
+        let more = if i + 1 < chunks.len() { 1 } else { 0 };
+        if i == 0 {
+            sequence.push_str(&format!(
+                "\x1b_Ga=T,f=100,t=d,q=2,C=1,c={},r={},m={};{}\x1b\\",
+                columns, rows, more, chunk
+            ));
+        } else {
+            sequence.push_str(&format!("\x1b_Gm={};{}\x1b\\", more, chunk));
+        }
+    }
+    sequence
+}
+
+fn iterm2_sequence(data: &[u8], columns: usize, rows: usize) -> String {
+    format!(
+        "\x1b]1337;File=inline=1;size={};width={};height={};preserveAspectRatio=1:{}\x07",
+        data.len(),
+        columns,
+        rows,
+        STANDARD.encode(data)
+    )
+}
+
+fn sixel_register(pixel: [u8; 4]) -> Option<usize> {
+    if pixel[3] < 128 {
+        return None;
+    }
+    let level = |v: u8| (v as usize * 5 + 127) / 255;
+    Some(36 * level(pixel[0]) + 6 * level(pixel[1]) + level(pixel[2]))
+}
+
+fn sixel_sequence(image: &Pixels) -> String {
+    let registers: Vec<Option<usize>> = (0..image.height)
+        .flat_map(|y| (0..image.width).map(move |x| (x, y)))
+        .map(|(x, y)| sixel_register(image.pixel(x, y)))
+        .collect();
+    
+    let mut sequence = format!("\x1bP0;1;0q\"1;1;{};{}", image.width, image.height);
+    
+    let used: BTreeSet<usize> = registers.iter().flatten().copied().collect();
+    for register in &used {
+        let percent = |level: usize| SIXEL_LEVELS[level] as usize * 100 / 255;
+        sequence.push_str(&format!(
+            "#{};2;{};{};{}",
+            register,
+            percent(register / 36),
+            percent(register / 6 % 6),
+            percent(register % 6)
+        ));
+    }
+    
+    for band in (0..image.height).step_by(6) {
+        let band_height = 6.min(image.height - band);
+        let band_registers: BTreeSet<usize> = (band..band + band_height)
+            .flat_map(|y| registers[y * image.width..(y + 1) * image.width].iter().flatten().copied())
+            .collect();
+        
+        for register in band_registers {
+            sequence.push_str(&format!("#{}", register));
+            
+            let sixels: Vec<u8> = (0..image.width)
+                .map(|x| {
+                    let bits = (0..band_height)
+                        .filter(|dy| registers[(band + dy) * image.width + x] == Some(register))
+                        .fold(0u8, |bits, dy| bits | (1 << dy));
+                    63 + bits
+                })
+                .collect();
+            push_sixel_runs(&mut sequence, &sixels);
+            sequence.push('$');
+        }
+        sequence.push('-');
+    }
+    
+    sequence.push_str("\x1b\\");
+    sequence
+}
+
+fn push_sixel_runs(sequence: &mut String, sixels: &[u8]) {
+    let mut i = 0;
+    while i < sixels.len() {
+        let run = sixels[i..].iter().take_while(|&&c| c == sixels[i]).count();
+        let ch = sixels[i] as char;
+        if run > 3 {
+            sequence.push_str(&format!("!{}{}", run, ch));
+        } else {
+            sequence.push_str(&ch.to_string().repeat(run));
+        }
+        i += run;
+    }
+}
+
+fn block_lines(image: &Pixels, depth: ColorDepth) -> Vec<String> {
+    (0..image.height / 2)
+        .map(|row| {
+            let mut line = String::new();
+            for x in 0..image.width {
+                let top = image.pixel(x, row * 2);
+                let bottom = image.pixel(x, row * 2 + 1);
+                let rgb = |p: [u8; 4]| (p[0], p[1], p[2]);
+                
+                match (top[3] >= 128, bottom[3] >= 128) {
+                    (false, false) => line.push_str("\x1b[0m "),
+                    (true, false) => line.push_str(&format!("\x1b[0;{}m▀", rgb_code(rgb(top), depth, false))),
+                    (false, true) => line.push_str(&format!("\x1b[0;{}m▄", rgb_code(rgb(bottom), depth, false))),
+                    (true, true) => line.push_str(&format!(
+                        "\x1b[0;{};{}m▀",
+                        rgb_code(rgb(top), depth, false),
+                        rgb_code(rgb(bottom), depth, true)
+                    )),
+                }
+            }
+            line.push_str("\x1b[0m");
+            line
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn fixture() -> Pixels {
+        Pixels {
+            width: 2,
+            height: 2,
+            rgba: vec![
+                255, 0, 0, 255,   0, 0, 0, 0,
+                0, 255, 0, 255,   0, 0, 255, 255,
+            ],
+        }
+    }
+    
+    #[test]
+    fn kitty_single_chunk() {
+        assert_eq!(
+            kitty_sequence(b"png", 4, 2),
+            "\x1b_Ga=T,f=100,t=d,q=2,C=1,c=4,r=2,m=0;cG5n\x1b\\"
+        );
+    }
+    
+    #[test]
+    fn kitty_splits_into_chunks() {
+        let data = vec![0u8; 6144];
+        let sequence = kitty_sequence(&data, 30, 15);
+        let parts: Vec<&str> = sequence.split("\x1b\\").filter(|p| !p.is_empty()).collect();
+        
+        assert_eq!(parts.len(), 2);
+        assert!(parts[0].starts_with("\x1b_Ga=T,f=100,t=d,q=2,C=1,c=30,r=15,m=1;"));
+        assert!(parts[1].starts_with("\x1b_Gm=0;"));
+        assert_eq!(parts[0].split_once(';').map(|(_, payload)| payload.len()), Some(KITTY_CHUNK_SIZE));
+        assert_eq!(parts[1].len() - "\x1b_Gm=0;".len(), 8192 - KITTY_CHUNK_SIZE);
+    }
+    
+    #[test]
+    fn iterm2_inline_file() {
+        assert_eq!(
+            iterm2_sequence(b"png", 4, 2),
+            "\x1b]1337;File=inline=1;size=3;width=4;height=2;preserveAspectRatio=1:cG5n\x07"
+        );
+    }
+    
+    #[test]
+    fn sixel_fixture() {
+        assert_eq!(
+            sixel_sequence(&fixture()),
+            "\x1bP0;1;0q\"1;1;2;2#5;2;0;0;100#30;2;0;100;0#180;2;100;0;0#5?A$#30A?$#180@?$-\x1b\\"
+        );
+    }
+    
+    #[test]
+    fn sixel_run_length() {
+        let mut sequence = String::new();
+        push_sixel_runs(&mut sequence, b"~~~~~@@");
+        assert_eq!(sequence, "!5~@@");
+    }
+    
+    #[test]
+    fn half_block_fixture() {
+        assert_eq!(
+            block_lines(&fixture(), ColorDepth::TrueColor),
+            vec![
+                "\x1b[0;38;2;255;0;0;48;2;0;255;0m▀\x1b[0;38;2;0;0;255m▄\x1b[0m".to_string(),
+            ]
+        );
+    }
+}
@@ -18,105 +18,382 @@
 pub mod colors;
 pub mod progress;
 pub mod icons;
+pub mod terminal;
+pub mod image;
+pub mod template;
+pub mod plain;
+pub mod structured;
 
 use crate::modules::SystemInfo;
 use crate::cli::Args;
+use crate::features::hardware_age;
 use colors::Theme;
-use unicode_width::UnicodeWidthStr;
+pub use template::Templates;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
-pub fn render(logo: &[String], info: &SystemInfo, theme: &Theme, args: &Args) {
-    if args.json {
-        render_json(info);
-        return;
+pub const MIN_INFO_WIDTH: usize = 40;
+
+pub enum Logo {
+    Text(Vec<String>),
+    Image(image::ImageLogo),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, clap::ValueEnum)]
+pub enum LogoPosition {
+    Left,
+    Right,
+    #[value(alias = "above")]
+    Top,
+    #[value(alias = "none", alias = "off")]
+    Hidden,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Layout {
+    position: LogoPosition,
+    padding_left: usize,
+    padding_right: usize,
+    padding_top: usize,
+}
+
+impl Layout {
+    fn from_args(args: &Args, logo_width: usize) -> Self {
+        let requested = args.logo_position;
+        let (default_left, default_right) = match requested {
+            Some(LogoPosition::Right) => (2, 0),
+            _ => (0, 2),
+        };
+        let padding_left = args.logo_padding_left.unwrap_or(default_left);
+        let padding_right = args.logo_padding_right.unwrap_or(default_right);
+        
+        let position = match requested {
+            Some(position) => position,
+            None if logo_width > 0 && !args.pipe && terminal::width()
+                .is_some_and(|width| width < padding_left + logo_width + padding_right + MIN_INFO_WIDTH) => LogoPosition::Top,
+            None => LogoPosition::Left,
+        };
+        
+        Layout {
+            position,
+            padding_left,
+            padding_right,
+            padding_top: args.logo_padding_top.unwrap_or(0),
+        }
+    }
+    
+    fn info_columns(&self, logo_width: usize) -> Option<usize> {
+        let width = terminal::width()?;
+        let used = match self.position {
+            LogoPosition::Top | LogoPosition::Hidden => self.padding_left,
+            _ if logo_width == 0 => self.padding_right,
+            _ => self.padding_left + logo_width + self.padding_right,
+        };
+        Some(width.saturating_sub(used).max(1))
+    }
+}
+
+pub fn render(logo: &Logo, info: &SystemInfo, theme: &Theme, args: &Args, templates: &Templates) {
+    print!("{}", render_to_string(logo, info, theme, args, templates));
+}
+
+pub fn render_to_string(logo: &Logo, info: &SystemInfo, theme: &Theme, args: &Args, templates: &Templates) -> String {
+    if args.oneline {
+        return format!("{}\n", plain::render_oneline(info, theme, args, templates));
     }
     
-    let info_lines = build_info_lines(info, theme, args);
-    let max_lines = logo.len().max(info_lines.len());
+    let info_lines = build_info_lines(info, theme, args, templates);
     
-    let logo_width = logo.iter()
+    let logo_width = match logo {
+        Logo::Text(lines) => max_width(lines),
+        Logo::Image(image) => image.columns,
+    };
+    let layout = Layout::from_args(args, logo_width);
+    let columns = layout.info_columns(logo_width).filter(|_| !args.pipe);
+    let info_lines = match columns {
+        Some(columns) => fit_lines(&info_lines, columns, args.wrap),
+        None => info_lines,
+    };
+    
+    let mut out = "\n".repeat(layout.padding_top);
+    
+    match logo {
+        Logo::Text(lines) if layout.position == LogoPosition::Top => {
+            let lines = match columns {
+                Some(columns) => fit_lines(lines, columns, false),
+                None => lines.clone(),
+            };
+            render_text(&mut out, &lines, &info_lines, &layout)
+        }
+        Logo::Text(lines) => render_text(&mut out, lines, &info_lines, &layout),
+        Logo::Image(image) => render_image(&mut out, image, &info_lines, &layout),
+    }
+    
+    out
+}
+
+fn max_width(lines: &[String]) -> usize {
+    lines.iter()
         .map(|line| strip_ansi_codes(line).width())
         .max()
-        .unwrap_or(0);
+        .unwrap_or(0)
+}
+
+fn pad_to(line: &str, width: usize) -> String {
+    let visible_width = strip_ansi_codes(line).width();
+    format!("{}{}", line, " ".repeat(width.saturating_sub(visible_width)))
+}
+
+fn cursor_forward(columns: usize) -> String {
+    if columns == 0 {
+        String::new()
+    } else {
+        format!("\x1b[{}C", columns)
+    }
+}
+
+fn centered(lines: &[String], row: usize, rows: usize) -> Option<&String> {
+    let offset = (rows - lines.len()) / 2;
+    row.checked_sub(offset).and_then(|i| lines.get(i))
+}
+
+fn render_info_below(out: &mut String, info_lines: &[String], layout: &Layout) {
+    for line in info_lines {
+        out.push_str(&format!("{}{}\n", " ".repeat(layout.padding_left), line));
+    }
+}
+
+fn render_text(out: &mut String, logo: &[String], info_lines: &[String], layout: &Layout) {
+    let logo_width = max_width(logo);
+    
+    if layout.position == LogoPosition::Top {
+        for line in logo {
+            out.push_str(&format!("{}{}\n", " ".repeat(layout.padding_left), line));
+        }
+        if !logo.is_empty() {
+            out.push('\n');
+        }
+        render_info_below(out, info_lines, layout);
+        return;
+    }
+    
+    let info_width = max_width(info_lines);
+    let rows = logo.len().max(info_lines.len());
     
-    for i in 0..max_lines {
+    for row in 0..rows {
+        let logo_cell = pad_to(centered(logo, row, rows).map(String::as_str).unwrap_or(""), logo_width);
+        let info_line = centered(info_lines, row, rows).map(String::as_str).unwrap_or("");
         
-        if i < logo.len() {
-            let line = &logo[i];
-            let visible_width = strip_ansi_codes(line).width();
-            let padding = logo_width.saturating_sub(visible_width);
-            print!("{}{}", line, " ".repeat(padding));
+        if layout.position == LogoPosition::Right {
+            let logo_cell = logo_cell.trim_end();
+            if logo_cell.is_empty() {
+                out.push_str(info_line);
+                out.push('\n');
+            } else {
+                out.push_str(&format!("{}{}{}\n",
+                    pad_to(info_line, info_width),
+                    " ".repeat(layout.padding_left),
+                    logo_cell
+                ));
+            }
         } else {
-            print!("{}", " ".repeat(logo_width));
+            out.push_str(&format!("{}{}{}{}\n",
+                " ".repeat(layout.padding_left),
+                logo_cell,
+                " ".repeat(layout.padding_right),
+                info_line
+            ));
         }
+    }
+}
+
+fn render_image(out: &mut String, image: &image::ImageLogo, info_lines: &[String], layout: &Layout) {
+    if layout.position == LogoPosition::Top {
+        out.push_str(&format!("{}\x1b[{}A", "\n".repeat(image.rows), image.rows));
+        out.push_str(&format!("\x1b7{}{}\x1b8", cursor_forward(layout.padding_left), image.sequence));
+        out.push_str(&format!("{}\n", "\n".repeat(image.rows)));
+        render_info_below(out, info_lines, layout);
+        return;
+    }
+    
+    let info_width = max_width(info_lines);
+    let rows = image.rows.max(info_lines.len());
+    let image_row = (rows - image.rows) / 2;
+    let image_column = match layout.position {
+        LogoPosition::Right => info_width + layout.padding_left,
+        _ => layout.padding_left,
+    };
+    
+    out.push_str(&format!("{}\x1b[{}A", "\n".repeat(rows), rows));
+    out.push_str(&format!("\x1b7{}{}",
+        if image_row > 0 { format!("\x1b[{}B", image_row) } else { String::new() },
+        cursor_forward(image_column)
+    ));
+    out.push_str(&format!("{}\x1b8", image.sequence));
+    
+    for row in 0..rows {
+        let info_line = centered(info_lines, row, rows).map(String::as_str).unwrap_or("");
         
-        print!("  ");
-        
-        if i < info_lines.len() {
-            println!("{}", info_lines[i]);
+        if layout.position == LogoPosition::Right {
+            out.push_str(info_line);
+            out.push('\n');
         } else {
-            println!();
+            out.push_str(&format!("{}{}{}{}\n",
+                " ".repeat(layout.padding_left),
+                cursor_forward(image.columns),
+                " ".repeat(layout.padding_right),
+                info_line
+            ));
         }
     }
 }
 
-fn build_info_lines(info: &SystemInfo, theme: &Theme, args: &Args) -> Vec<String> {
+fn build_info_lines(info: &SystemInfo, theme: &Theme, args: &Args, templates: &Templates) -> Vec<String> {
     let mut lines = Vec::new();
-    let use_icons = !args.no_icons;
+    let use_icons = !args.no_icons && !args.pipe;
+    let value = |module: &str, default: &str| {
+        info.fields(module)
+            .and_then(|fields| templates.apply(module, fields, theme))
+            .unwrap_or_else(|| theme.apply_value(default))
+    };
     
-    let title = format!("{}", info.user.display());
-    lines.push(theme.apply_title(&title));
-    lines.push(theme.apply_separator(&"─".repeat(title.width())));
+    let title = templates.apply("user", info.user.fields(), theme)
+        .unwrap_or_else(|| theme.apply_title(&info.user.display()));
+    lines.push(theme.apply_separator(&"─".repeat(strip_ansi_codes(&title).width())));
+    lines.insert(0, title);
     
     if use_icons {
         lines.push(format!("{} {}  {}", 
             theme.apply_label("󰣇"), 
             theme.apply_label("OS"), 
-            theme.apply_value(&info.os.display())
+            value("os", &info.os.display())
         ));
     } else {
         lines.push(format!("{}  {}", 
             theme.apply_label("OS"), 
-            theme.apply_value(&info.os.display())
+            value("os", &info.os.display())
         ));
     }
     
+    if info.host.has_host() {
+        if use_icons {
+            lines.push(format!("{} {}  {}",
+                theme.apply_label("󰌢"),
+                theme.apply_label("Host"),
+                value("host", &info.host.display())
+            ));
+        } else {
+            lines.push(format!("{}  {}",
+                theme.apply_label("Host"),
+                value("host", &info.host.display())
+            ));
+        }
+    }
+    
+    if let Some(board) = info.host.display_board() {
+        if use_icons {
+            lines.push(format!("{} {}  {}",
+                theme.apply_label("󰘚"),
+                theme.apply_label("Board"),
+                theme.apply_value(&board)
+            ));
+        } else {
+            lines.push(format!("{}  {}",
+                theme.apply_label("Board"),
+                theme.apply_value(&board)
+            ));
+        }
+    }
+    
+    if let Some(bios) = info.host.display_bios() {
+        if use_icons {
+            lines.push(format!("{} {}  {}",
+                theme.apply_label("󰒓"),
+                theme.apply_label("BIOS"),
+                theme.apply_value(&bios)
+            ));
+        } else {
+            lines.push(format!("{}  {}",
+                theme.apply_label("BIOS"),
+                theme.apply_value(&bios)
+            ));
+        }
+    }
+    
     if use_icons {
         lines.push(format!("{} {}  {}", 
             theme.apply_label(""), 
             theme.apply_label("Kernel"), 
-            theme.apply_value(&info.kernel.display())
+            value("kernel", &info.kernel.display())
         ));
     } else {
         lines.push(format!("{}  {}", 
             theme.apply_label("Kernel"), 
-            theme.apply_value(&info.kernel.display())
+            value("kernel", &info.kernel.display())
         ));
     }
     
+    if info.virtualization.is_detected() {
+        if use_icons {
+            lines.push(format!("{} {}  {}",
+                theme.apply_label("󰬿"),
+                theme.apply_label("Virtualization"),
+                value("virtualization", &info.virtualization.display())
+            ));
+        } else {
+            lines.push(format!("{}  {}",
+                theme.apply_label("Virtualization"),
+                value("virtualization", &info.virtualization.display())
+            ));
+        }
+    }
+    
     if use_icons {
         lines.push(format!("{} {}  {}", 
             theme.apply_label("󰌽"), 
             theme.apply_label("CPU"), 
-            theme.apply_value(&info.cpu.display())
+            value("cpu", &info.cpu.display())
         ));
     } else {
         lines.push(format!("{}  {}", 
             theme.apply_label("CPU"), 
-            theme.apply_value(&info.cpu.display())
+            value("cpu", &info.cpu.display())
         ));
     }
     
     if !info.gpu.gpus.is_empty() {
+        let gpu = match templates.get("gpu") {
+            Some(format) => info.gpu.gpus.iter()
+                .map(|gpu| template::render(format, &gpu.fields(), theme))
+                .collect::<Vec<_>>()
+                .join(", "),
+            None => theme.apply_value(&info.gpu.display()),
+        };
         if use_icons {
             lines.push(format!("{} {}  {}", 
                 theme.apply_label(""), 
                 theme.apply_label("GPU"), 
-                theme.apply_value(&info.gpu.display())
+                gpu
             ));
         } else {
             lines.push(format!("{}  {}", 
                 theme.apply_label("GPU"), 
-                theme.apply_value(&info.gpu.display())
+                gpu
+            ));
+        }
+    }
+    
+    let gpu_models: Vec<&str> = info.gpu.gpus.iter().map(|g| g.model.as_str()).collect();
+    if let Some(age) = hardware_age::estimate_hardware_age(&info.cpu.model, &gpu_models, info.host.bios_year(), hardware_age::current_year()) {
+        if use_icons {
+            lines.push(format!("{} {}  {}",
+                theme.apply_label("󰅐"),
+                theme.apply_label("Hardware Age"),
+                theme.apply_value(&age)
+            ));
+        } else {
+            lines.push(format!("{}  {}",
+                theme.apply_label("Hardware Age"),
+                theme.apply_value(&age)
             ));
         }
     }
@@ -126,13 +403,13 @@ fn build_info_lines(info: &SystemInfo, theme: &Theme, args: &Args) -> Vec<String
         lines.push(format!("{} {}  {} {}",
             theme.apply_label(""),
             theme.apply_label("Memory"),
-            theme.apply_value(&info.memory.display()),
+            value("memory", &info.memory.display()),
             mem_bar
         ));
     } else {
         lines.push(format!("{}  {} {}",
             theme.apply_label("Memory"),
-            theme.apply_value(&info.memory.display()),
+            value("memory", &info.memory.display()),
             mem_bar
         ));
     }
@@ -142,40 +419,127 @@ fn build_info_lines(info: &SystemInfo, theme: &Theme, args: &Args) -> Vec<String
         lines.push(format!("{} {}  {} {}",
             theme.apply_label("󰋊"),
             theme.apply_label("Disk"),
-            theme.apply_value(&info.disk.display()),
+            value("disk", &info.disk.display()),
             disk_bar
         ));
     } else {
         lines.push(format!("{}  {} {}",
             theme.apply_label("Disk"),
-            theme.apply_value(&info.disk.display()),
+            value("disk", &info.disk.display()),
             disk_bar
         ));
     }
     
+    let uptime = info.uptime.display_as(args.uptime_format);
     if use_icons {
         lines.push(format!("{} {}  {}",
             theme.apply_label(""),
             theme.apply_label("Uptime"),
-            theme.apply_value(&info.uptime.display())
+            value("uptime", &uptime)
         ));
     } else {
         lines.push(format!("{}  {}",
             theme.apply_label("Uptime"),
-            theme.apply_value(&info.uptime.display())
+            value("uptime", &uptime)
+        ));
+    }
+    
+    if args.all {
+        if let Some(boot) = info.uptime.display_boot() {
+            if use_icons {
+                lines.push(format!("{} {}  {}",
+                    theme.apply_label("󰥔"),
+                    theme.apply_label("Boot Time"),
+                    theme.apply_value(&boot)
+                ));
+            } else {
+                lines.push(format!("{}  {}",
+                    theme.apply_label("Boot Time"),
+                    theme.apply_value(&boot)
+                ));
+            }
+        }
+        
+        if let Some(session) = info.uptime.display_session() {
+            if use_icons {
+                lines.push(format!("{} {}  {}",
+                    theme.apply_label("󰀄"),
+                    theme.apply_label("Session"),
+                    theme.apply_value(&session)
+                ));
+            } else {
+                lines.push(format!("{}  {}",
+                    theme.apply_label("Session"),
+                    theme.apply_value(&session)
+                ));
+            }
+        }
+    }
+    
+    if let Some(values) = info.load.load_values() {
+        let load = templates.apply("load", info.load.fields(), theme).unwrap_or_else(|| values.iter()
+            .map(|value| theme.paint(
+                &format!("{:.2}", value),
+                progress::severity_color(info.load.load_severity(*value), theme)
+            ))
+            .collect::<Vec<_>>()
+            .join(", "));
+        if use_icons {
+            lines.push(format!("{} {}  {}",
+                theme.apply_label("󰄨"),
+                theme.apply_label("Load"),
+                load
+            ));
+        } else {
+            lines.push(format!("{}  {}",
+                theme.apply_label("Load"),
+                load
+            ));
+        }
+    }
+    
+    if use_icons {
+        lines.push(format!("{} {}  {}",
+            theme.apply_label("󰓇"),
+            theme.apply_label("Processes"),
+            theme.apply_value(&info.load.display_processes())
         ));
+    } else {
+        lines.push(format!("{}  {}",
+            theme.apply_label("Processes"),
+            theme.apply_value(&info.load.display_processes())
+        ));
+    }
+    
+    if !info.load.pressure.is_empty() {
+        let pressure = info.load.pressure.iter()
+            .map(|p| theme.paint(&p.display(), progress::severity_color(p.severity(), theme)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        if use_icons {
+            lines.push(format!("{} {}  {}",
+                theme.apply_label("󰊚"),
+                theme.apply_label("Pressure"),
+                pressure
+            ));
+        } else {
+            lines.push(format!("{}  {}",
+                theme.apply_label("Pressure"),
+                pressure
+            ));
+        }
     }
     
     if use_icons {
         lines.push(format!("{} {}  {}",
             theme.apply_label("󰆍"),
             theme.apply_label("Shell"),
-            theme.apply_value(&info.shell.display())
+            value("shell", &info.shell.display())
         ));
     } else {
         lines.push(format!("{}  {}",
             theme.apply_label("Shell"),
-            theme.apply_value(&info.shell.display())
+            value("shell", &info.shell.display())
         ));
     }
     
@@ -184,12 +548,87 @@ fn build_info_lines(info: &SystemInfo, theme: &Theme, args: &Args) -> Vec<String
             lines.push(format!("{} {}  {}",
                 theme.apply_label(""),
                 theme.apply_label("DE"),
-                theme.apply_value(&info.desktop.display())
+                value("desktop", &info.desktop.display())
             ));
         } else {
             lines.push(format!("{}  {}",
                 theme.apply_label("DE"),
-                theme.apply_value(&info.desktop.display())
+                value("desktop", &info.desktop.display())
+            ));
+        }
+    }
+    
+    if info.desktop.has_separate_wm() {
+        if use_icons {
+            lines.push(format!("{} {}  {}",
+                theme.apply_label(""),
+                theme.apply_label("WM"),
+                theme.apply_value(&info.desktop.display_wm())
+            ));
+        } else {
+            lines.push(format!("{}  {}",
+                theme.apply_label("WM"),
+                theme.apply_value(&info.desktop.display_wm())
+            ));
+        }
+    }
+    
+    if let Some(desktop_theme) = info.desktop.display_theme() {
+        if use_icons {
+            lines.push(format!("{} {}  {}",
+                theme.apply_label("󰏘"),
+                theme.apply_label("Theme"),
+                theme.apply_value(&desktop_theme)
+            ));
+        } else {
+            lines.push(format!("{}  {}",
+                theme.apply_label("Theme"),
+                theme.apply_value(&desktop_theme)
+            ));
+        }
+    }
+    
+    if let Some(icons) = info.desktop.icons.clone() {
+        if use_icons {
+            lines.push(format!("{} {}  {}",
+                theme.apply_label("󰲢"),
+                theme.apply_label("Icons"),
+                theme.apply_value(&icons)
+            ));
+        } else {
+            lines.push(format!("{}  {}",
+                theme.apply_label("Icons"),
+                theme.apply_value(&icons)
+            ));
+        }
+    }
+    
+    if let Some(cursor) = info.desktop.display_cursor() {
+        if use_icons {
+            lines.push(format!("{} {}  {}",
+                theme.apply_label("󰆿"),
+                theme.apply_label("Cursor"),
+                theme.apply_value(&cursor)
+            ));
+        } else {
+            lines.push(format!("{}  {}",
+                theme.apply_label("Cursor"),
+                theme.apply_value(&cursor)
+            ));
+        }
+    }
+    
+    if let Some(font) = info.desktop.display_font() {
+        if use_icons {
+            lines.push(format!("{} {}  {}",
+                theme.apply_label("󰛖"),
+                theme.apply_label("Font"),
+                theme.apply_value(&font)
+            ));
+        } else {
+            lines.push(format!("{}  {}",
+                theme.apply_label("Font"),
+                theme.apply_value(&font)
             ));
         }
     }
@@ -200,45 +639,107 @@ fn build_info_lines(info: &SystemInfo, theme: &Theme, args: &Args) -> Vec<String
                 lines.push(format!("{} {}  {}",
                     theme.apply_label("󰍹"),
                     theme.apply_label("Display"),
-                    theme.apply_value(&primary.display())
+                    value("display", &primary.display())
                 ));
             } else {
                 lines.push(format!("{}  {}",
                     theme.apply_label("Display"),
-                    theme.apply_value(&primary.display())
+                    value("display", &primary.display())
+                ));
+            }
+        }
+    }
+    
+    let multiple_batteries = info.battery.batteries.len() > 1;
+    for bat in &info.battery.batteries {
+        let label = if multiple_batteries {
+            format!("Battery ({})", bat.name)
+        } else {
+            "Battery".to_string()
+        };
+        let bat_display = match templates.apply("battery", bat.fields(), theme) {
+            Some(value) => format!("{} {}", value, theme.apply_value(&bat.get_bar(10))),
+            None => theme.apply_value(&format!("{} {}", bat.display(), bat.get_bar(10))),
+        };
+        
+        if use_icons {
+            lines.push(format!("{} {}  {}",
+                theme.apply_label(icons::get_battery_icon(bat.percentage, bat.is_charging())),
+                theme.apply_label(&label),
+                bat_display
+            ));
+        } else {
+            lines.push(format!("{}  {}",
+                theme.apply_label(&label),
+                bat_display
+            ));
+        }
+        
+        if let Some(details) = bat.display_details() {
+            if use_icons {
+                lines.push(format!("{} {}  {}",
+                    theme.apply_label(icons::ICON_BATTERY_FULL),
+                    theme.apply_label("Battery Health"),
+                    theme.apply_value(&details)
+                ));
+            } else {
+                lines.push(format!("{}  {}",
+                    theme.apply_label("Battery Health"),
+                    theme.apply_value(&details)
                 ));
             }
         }
     }
     
     if info.battery.has_battery {
-        if let Some(bat_display) = info.battery.display() {
+        if let Some(ac) = info.battery.display_ac() {
             if use_icons {
                 lines.push(format!("{} {}  {}",
-                    theme.apply_label("󰂄"),
-                    theme.apply_label("Battery"),
-                    theme.apply_value(&bat_display)
+                    theme.apply_label("󰚥"),
+                    theme.apply_label("Power Adapter"),
+                    theme.apply_value(&ac)
                 ));
             } else {
                 lines.push(format!("{}  {}",
-                    theme.apply_label("Battery"),
-                    theme.apply_value(&bat_display)
+                    theme.apply_label("Power Adapter"),
+                    theme.apply_value(&ac)
                 ));
             }
         }
     }
     
+    for device in &info.battery.peripherals {
+        let label = format!("Battery ({})", device.label());
+        let device_display = match templates.apply("battery", device.fields(), theme) {
+            Some(value) => format!("{} {}", value, theme.apply_value(&device.get_bar(10))),
+            None => theme.apply_value(&format!("{} {}", device.display(), device.get_bar(10))),
+        };
+        
+        if use_icons {
+            lines.push(format!("{} {}  {}",
+                theme.apply_label(icons::get_battery_icon(device.percentage, device.is_charging())),
+                theme.apply_label(&label),
+                device_display
+            ));
+        } else {
+            lines.push(format!("{}  {}",
+                theme.apply_label(&label),
+                device_display
+            ));
+        }
+    }
+    
     if info.packages.total > 0 {
         if use_icons {
             lines.push(format!("{} {}  {}",
                 theme.apply_label(""),
                 theme.apply_label("Packages"),
-                theme.apply_value(&info.packages.display())
+                value("packages", &info.packages.display())
             ));
         } else {
             lines.push(format!("{}  {}",
                 theme.apply_label("Packages"),
-                theme.apply_value(&info.packages.display())
+                value("packages", &info.packages.display())
             ));
         }
     }
@@ -247,15 +748,45 @@ fn build_info_lines(info: &SystemInfo, theme: &Theme, args: &Args) -> Vec<String
         lines.push(format!("{} {}  {}",
             theme.apply_label(""),
             theme.apply_label("Terminal"),
-            theme.apply_value(&info.shell.display_terminal())
+            value("terminal", &info.shell.display_terminal())
         ));
     } else {
         lines.push(format!("{}  {}",
             theme.apply_label("Terminal"),
-            theme.apply_value(&info.shell.display_terminal())
+            value("terminal", &info.shell.display_terminal())
         ));
     }
     
+    if let Some(throughput) = info.network.display_throughput() {
+        if use_icons {
+            lines.push(format!("{} {}  {}",
+                theme.apply_label("󰛳"),
+                theme.apply_label("Network"),
+                value("network", &throughput)
+            ));
+        } else {
+            lines.push(format!("{}  {}",
+                theme.apply_label("Network"),
+                value("network", &throughput)
+            ));
+        }
+    }
+    
+    if let Some(font) = &info.shell.terminal_font {
+        if use_icons {
+            lines.push(format!("{} {}  {}",
+                theme.apply_label("󰛖"),
+                theme.apply_label("Terminal Font"),
+                theme.apply_value(font)
+            ));
+        } else {
+            lines.push(format!("{}  {}",
+                theme.apply_label("Terminal Font"),
+                theme.apply_value(font)
+            ));
+        }
+    }
+    
     lines.push(String::new());
     
     lines.push(theme.apply_color_blocks());
@@ -263,37 +794,147 @@ fn build_info_lines(info: &SystemInfo, theme: &Theme, args: &Args) -> Vec<String
     lines
 }
 
-fn strip_ansi_codes(s: &str) -> String {
+fn split_escapes(s: &str) -> Vec<(bool, &str)> {
+    let mut segments = Vec::new();
+    let mut rest = s;
+    
+    while let Some(start) = rest.find('\x1b') {
+        if start > 0 {
+            segments.push((false, &rest[..start]));
+        }
+        let mut len = escape_len(&rest[start..]);
+        while !rest[start..].is_char_boundary(len) {
+            len += 1;
+        }
+        segments.push((true, &rest[start..start + len]));
+        rest = &rest[start + len..];
+    }
+    if !rest.is_empty() {
+        segments.push((false, rest));
+    }
+    
+    segments
+}
+
+fn escape_len(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let string_end = |from: usize, bell: bool| {
+        (from..bytes.len())
+            .find_map(|i| match bytes[i] {
+                0x07 if bell => Some(i + 1),
+                0x1b if bytes.get(i + 1) == Some(&b'\\') => Some(i + 2),
+                _ => None,
+            })
+            .unwrap_or(bytes.len())
+    };
+    
+    match bytes.get(1) {
+        Some(b'[') => (2..bytes.len())
+            .find(|&i| (0x40..=0x7e).contains(&bytes[i]))
+            .map(|i| i + 1)
+            .unwrap_or(bytes.len()),
+        Some(b']') => string_end(2, true),
+        Some(b'P' | b'X' | b'^' | b'_') => string_end(2, false),
+        Some(_) => (1..bytes.len())
+            .find(|&i| !(0x20..=0x2f).contains(&bytes[i]))
+            .map(|i| i + 1)
+            .unwrap_or(bytes.len()),
+        None => 1,
+    }
+}
+
+fn truncate_line(line: &str, width: usize) -> String {
+    if strip_ansi_codes(line).width() <= width {
+        return line.to_string();
+    }
     
+    let budget = width.saturating_sub(1);
     let mut result = String::new();
-    let mut in_escape = false;
-    
-    for ch in s.chars() {
-        if ch == '\x1b' {
-            in_escape = true;
-        } else if in_escape && ch == 'm' {
-            in_escape = false;
-        } else if !in_escape {
+    let mut used = 0;
+    let mut styled = false;
+    
+    'segments: for (is_escape, segment) in split_escapes(line) {
+        if is_escape {
+            styled = true;
+            result.push_str(segment);
+            continue;
+        }
+        for ch in segment.chars() {
+            let ch_width = ch.width().unwrap_or(0);
+            if used + ch_width > budget {
+                break 'segments;
+            }
+            used += ch_width;
             result.push(ch);
         }
     }
     
+    if width > 0 {
+        result.push('…');
+    }
+    if styled {
+        result.push_str("\x1b[0m");
+    }
     result
 }
 
-fn render_json(info: &SystemInfo) {
-    
-    println!("{{");
-    println!("  \"user\": \"{}\",", info.user.display());
-    println!("  \"os\": \"{}\",", info.os.display());
-    println!("  \"kernel\": \"{}\",", info.kernel.display());
-    println!("  \"cpu\": \"{}\",", info.cpu.display());
-    println!("  \"gpu\": \"{}\",", info.gpu.display());
-    println!("  \"memory\": \"{}\",", info.memory.display());
-    println!("  \"disk\": \"{}\",", info.disk.display());
-    println!("  \"uptime\": \"{}\",", info.uptime.display());
-    println!("  \"shell\": \"{}\",", info.shell.display());
-    println!("  \"desktop\": \"{}\",", info.desktop.display());
-    println!("  \"packages\": \"{}\"", info.packages.display());
-    println!("}}");
+fn wrap_line(line: &str, width: usize) -> Vec<String> {
+    if strip_ansi_codes(line).width() <= width {
+        return vec![line.to_string()];
+    }
+    
+    let plain = strip_ansi_codes(line);
+    let indent = plain.find("  ")
+        .map(|index| plain[..index + 2].width())
+        .filter(|indent| *indent < width / 2)
+        .unwrap_or(0);
+    
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    let mut active = String::new();
+    let mut used = 0;
+    
+    for (is_escape, segment) in split_escapes(line) {
+        if is_escape {
+            if segment == "\x1b[0m" || segment == "\x1b[m" {
+                active.clear();
+            } else if segment.ends_with('m') {
+                active.push_str(segment);
+            }
+            current.push_str(segment);
+            continue;
+        }
+        for ch in segment.chars() {
+            let ch_width = ch.width().unwrap_or(0);
+            if used + ch_width > width {
+                if !active.is_empty() {
+                    current.push_str("\x1b[0m");
+                }
+                lines.push(std::mem::take(&mut current));
+                current = format!("{}{}", " ".repeat(indent), active);
+                used = indent;
+            }
+            used += ch_width;
+            current.push(ch);
+        }
+    }
+    lines.push(current);
+    
+    lines
+}
+
+fn fit_lines(lines: &[String], width: usize, wrap: bool) -> Vec<String> {
+    if wrap {
+        lines.iter().flat_map(|line| wrap_line(line, width)).collect()
+    } else {
+        lines.iter().map(|line| truncate_line(line, width)).collect()
+    }
+}
+
+fn strip_ansi_codes(s: &str) -> String {
+    split_escapes(s)
+        .into_iter()
+        .filter(|(is_escape, _)| !is_escape)
+        .map(|(_, text)| text)
+        .collect()
 }
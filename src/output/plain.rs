@@ -0,0 +1,116 @@
+/*
+* Copyright (C) 2026 compiledkernel-idk <https://github.com/compiledkernel-idk>
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program. If not, see <https://www.gnu.org/licenses/>
+*/
+
+use crate::cli::Args;
+use crate::modules::{SystemInfo, Value, MODULES};
+use super::colors::Theme;
+use super::template::{self, Templates};
+
+pub const DEFAULT_ONELINE_MODULES: &str = "os,kernel,cpu,memory,uptime";
+pub const DEFAULT_SEPARATOR: &str = " | ";
+
+pub fn render_oneline(info: &SystemInfo, theme: &Theme, args: &Args, templates: &Templates) -> String {
+    let modules = args.oneline_modules.as_deref()
+        .or_else(|| templates.oneline_setting("modules"))
+        .unwrap_or(DEFAULT_ONELINE_MODULES);
+    let separator = args.separator.as_deref()
+        .or_else(|| templates.oneline_setting("separator"))
+        .unwrap_or(DEFAULT_SEPARATOR);
+    
+    let parts: Vec<String> = modules.split(',')
+        .map(|module| module.trim().to_lowercase())
+        .filter_map(|module| {
+            let fields = info.fields(&module)?;
+            templates.apply(&module, fields, theme)
+                .or_else(|| info.display(&module).map(|text| theme.apply_value(&text)))
+        })
+        .collect();
+    
+    parts.join(&theme.apply_separator(separator))
+}
+
+pub fn print_field(info: &SystemInfo, query: &str) -> bool {
+    let (module, path) = match query.split_once('.') {
+        Some((module, path)) => (module.to_lowercase(), Some(path)),
+        None => (query.to_lowercase(), None),
+    };
+    
+    let Some(fields) = info.fields(&module) else {
+        eprintln!("Unknown module '{}'. Available: {}", module, MODULES.join(", "));
+        return false;
+    };
+    
+    let value = match path {
+        Some(path) => match template::lookup(&fields, path) {
+            Some(value) => value.to_string(),
+            None => {
+                let names: Vec<&str> = fields.iter().map(|(name, _)| *name).collect();
+                eprintln!("Unknown field '{}' for {}. Available: {}", path, module, names.join(", "));
+                return false;
+            }
+        },
+        None => info.display(&module).unwrap_or_default(),
+    };
+    
+    println!("{}", value);
+    true
+}
+
+pub fn render_key_value(info: &SystemInfo) {
+    for module in MODULES {
+        let Some(fields) = info.fields(module) else {
+            continue;
+        };
+        for (name, value) in &fields {
+            print_assignments(&format!("{}_{}", module, name), value);
+        }
+    }
+}
+
+fn print_assignments(key: &str, value: &Value) {
+    match value {
+        Value::List(items) if items.iter().any(|item| matches!(item, Value::Map(_) | Value::List(_))) => {
+            for (index, item) in items.iter().enumerate() {
+                print_assignments(&format!("{}_{}", key, index), item);
+            }
+        }
+        Value::Map(fields) => {
+            for (name, value) in fields {
+                print_assignments(&format!("{}_{}", key, name), value);
+            }
+        }
+        Value::List(items) => {
+            let items: Vec<String> = items.iter().map(|item| item.to_string()).collect();
+            println!("{}={}", shell_key(key), shell_quote(&items.join(" ")));
+        }
+        _ => println!("{}={}", shell_key(key), shell_quote(&value.to_string())),
+    }
+}
+
+fn shell_key(key: &str) -> String {
+    key.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+fn shell_quote(value: &str) -> String {
+    if !value.is_empty() && value.chars().all(|c| c.is_ascii_alphanumeric() || "@%+=:,./-_".contains(c)) {
+        value.to_string()
+    } else {
+        format!("'{}'", value.replace('\'', "'\\''"))
+    }
+}
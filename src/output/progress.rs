@@ -16,6 +16,7 @@
 */
 
 use super::colors::Theme;
+use crate::modules::load::Severity;
 use colored::*;
 
 pub fn create_bar(percentage: f32, width: usize, theme: &Theme) -> String {
@@ -31,12 +32,20 @@ pub fn create_bar(percentage: f32, width: usize, theme: &Theme) -> String {
         theme.bar_bad_color
     };
     
-    let filled_str = "█".repeat(filled).color(bar_color).to_string();
-    let empty_str = "░".repeat(empty).truecolor(80, 80, 80).to_string();
+    let filled_str = theme.paint(&"█".repeat(filled), bar_color);
+    let empty_str = theme.paint(&"░".repeat(empty), theme.separator_color);
     
     format!("[{}{}] {:.0}%", filled_str, empty_str, percentage)
 }
 
+pub fn severity_color(severity: Severity, theme: &Theme) -> Color {
+    match severity {
+        Severity::Good => theme.bar_good_color,
+        Severity::Warn => theme.bar_warn_color,
+        Severity::Bad => theme.bar_bad_color,
+    }
+}
+
 pub fn create_simple_bar(percentage: f32, width: usize) -> String {
     let filled = (percentage / 100.0 * width as f32) as usize;
     let filled = filled.min(width);
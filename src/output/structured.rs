@@ -0,0 +1,406 @@
+/*
+* Copyright (C) 2026 compiledkernel-idk <https://github.com/compiledkernel-idk>
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program. If not, see <https://www.gnu.org/licenses/>
+*/
+
+use std::collections::HashMap;
+use crate::modules::{Fields, SystemInfo, Value};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OutputFormat {
+    Json,
+    Yaml,
+    Toml,
+    Csv,
+    Prometheus,
+}
+
+impl OutputFormat {
+    pub const NAMES: &'static [&'static str] = &["json", "yaml", "toml", "csv", "prometheus"];
+    
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "json" => Some(OutputFormat::Json),
+            "yaml" | "yml" => Some(OutputFormat::Yaml),
+            "toml" => Some(OutputFormat::Toml),
+            "csv" => Some(OutputFormat::Csv),
+            "prometheus" | "prom" => Some(OutputFormat::Prometheus),
+            _ => None,
+        }
+    }
+}
+
+pub fn render(info: &SystemInfo, format: OutputFormat) {
+    println!("{}", to_string(info, format).trim_end());
+}
+
+pub fn to_string(info: &SystemInfo, format: OutputFormat) -> String {
+    let modules: Fields = info.all_fields()
+        .into_iter()
+        .map(|(module, fields)| (module, Value::Map(fields)))
+        .collect();
+    
+    match format {
+        OutputFormat::Json => to_json(&Value::Map(modules), 0),
+        OutputFormat::Yaml => to_yaml(&modules),
+        OutputFormat::Toml => to_toml(&modules),
+        OutputFormat::Csv => to_csv(&modules),
+        OutputFormat::Prometheus => to_prometheus(&modules),
+    }
+}
+
+fn number(value: f64) -> Option<String> {
+    value.is_finite().then(|| value.to_string())
+}
+
+pub fn json_string(text: &str) -> String {
+    let mut result = String::with_capacity(text.len() + 2);
+    result.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c if (c as u32) < 0x20 || c == '\u{7f}' => result.push_str(&format!("\\u{:04x}", c as u32)),
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
+
+fn scalar(value: &Value) -> String {
+    match value {
+        Value::Null => "null".to_string(),
+        Value::Bool(value) => value.to_string(),
+        Value::Int(value) => value.to_string(),
+        Value::Float(value) => number(*value).unwrap_or_else(|| "null".to_string()),
+        Value::Text(text) => json_string(text),
+        other => json_string(&other.to_string()),
+    }
+}
+
+pub fn to_json(value: &Value, depth: usize) -> String {
+    let indent = "  ".repeat(depth + 1);
+    let closing = "  ".repeat(depth);
+    
+    match value {
+        Value::List(items) if items.is_empty() => "[]".to_string(),
+        Value::Map(fields) if fields.is_empty() => "{}".to_string(),
+        Value::List(items) => {
+            let items: Vec<String> = items.iter()
+                .map(|item| format!("{}{}", indent, to_json(item, depth + 1)))
+                .collect();
+            format!("[\n{}\n{}]", items.join(",\n"), closing)
+        }
+        Value::Map(fields) => {
+            let fields: Vec<String> = fields.iter()
+                .map(|(name, value)| format!("{}{}: {}", indent, json_string(name), to_json(value, depth + 1)))
+                .collect();
+            format!("{{\n{}\n{}}}", fields.join(",\n"), closing)
+        }
+        other => scalar(other),
+    }
+}
+
+fn to_yaml(fields: &Fields) -> String {
+    let mut output = String::new();
+    write_yaml_map(fields, 0, &mut output);
+    output
+}
+
+fn write_yaml_map(fields: &Fields, depth: usize, output: &mut String) {
+    let indent = "  ".repeat(depth);
+    for (name, value) in fields {
+        match value {
+            Value::Map(inner) if !inner.is_empty() => {
+                output.push_str(&format!("{}{}:\n", indent, name));
+                write_yaml_map(inner, depth + 1, output);
+            }
+            Value::List(items) if !items.is_empty() => {
+                output.push_str(&format!("{}{}:\n", indent, name));
+                write_yaml_list(items, depth + 1, output);
+            }
+            other => output.push_str(&format!("{}{}: {}\n", indent, name, yaml_scalar(other))),
+        }
+    }
+}
+
+fn write_yaml_list(items: &[Value], depth: usize, output: &mut String) {
+    let indent = "  ".repeat(depth);
+    for item in items {
+        match item {
+            Value::Map(inner) if !inner.is_empty() => {
+                let mut nested = String::new();
+                write_yaml_map(inner, depth + 1, &mut nested);
+                let nested = nested.trim_start();
+                output.push_str(&format!("{}- {}", indent, nested));
+            }
+            Value::List(inner) if !inner.is_empty() => {
+                output.push_str(&format!("{}-\n", indent));
+                write_yaml_list(inner, depth + 1, output);
+            }
+            other => output.push_str(&format!("{}- {}\n", indent, yaml_scalar(other))),
+        }
+    }
+}
+
+fn yaml_scalar(value: &Value) -> String {
+    match value {
+        Value::Float(value) => number(*value).unwrap_or_else(|| ".nan".to_string()),
+        Value::List(_) => "[]".to_string(),
+        Value::Map(_) => "{}".to_string(),
+        other => scalar(other),
+    }
+}
+
+fn to_toml(modules: &Fields) -> String {
+    let mut output = String::new();
+    for (module, value) in modules {
+        if let Value::Map(fields) = value {
+            write_toml_table(module, fields, false, &mut output);
+        }
+    }
+    output
+}
+
+fn write_toml_table(path: &str, fields: &Fields, array: bool, output: &mut String) {
+    if array {
+        output.push_str(&format!("[[{}]]\n", path));
+    } else {
+        output.push_str(&format!("[{}]\n", path));
+    }
+    
+    for (name, value) in fields {
+        if let Some(value) = toml_value(value) {
+            output.push_str(&format!("{} = {}\n", name, value));
+        }
+    }
+    output.push('\n');
+    
+    for (name, value) in fields {
+        match value {
+            Value::Map(inner) if !inner.is_empty() => {
+                write_toml_table(&format!("{}.{}", path, name), inner, false, output);
+            }
+            Value::List(items) if items.iter().any(|item| matches!(item, Value::Map(_))) => {
+                for item in items {
+                    if let Value::Map(inner) = item {
+                        write_toml_table(&format!("{}.{}", path, name), inner, true, output);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn toml_value(value: &Value) -> Option<String> {
+    match value {
+        Value::Null => None,
+        Value::Float(value) => Some(number(*value).unwrap_or_else(|| "nan".to_string())),
+        Value::Map(fields) if fields.is_empty() => Some("{}".to_string()),
+        Value::Map(_) => None,
+        Value::List(items) if items.iter().any(|item| matches!(item, Value::Map(_))) => None,
+        Value::List(items) => {
+            let items: Vec<String> = items.iter().filter_map(toml_value).collect();
+            Some(format!("[{}]", items.join(", ")))
+        }
+        other => Some(scalar(other)),
+    }
+}
+
+fn flatten(prefix: &str, value: &Value, columns: &mut Vec<(String, String)>) {
+    match value {
+        Value::Map(fields) => {
+            for (name, value) in fields {
+                flatten(&format!("{}.{}", prefix, name), value, columns);
+            }
+        }
+        Value::List(items) if items.iter().any(|item| matches!(item, Value::Map(_) | Value::List(_))) => {
+            for (index, item) in items.iter().enumerate() {
+                flatten(&format!("{}.{}", prefix, index), item, columns);
+            }
+        }
+        other => columns.push((prefix.to_string(), other.to_string())),
+    }
+}
+
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+fn to_csv(modules: &Fields) -> String {
+    let mut columns = Vec::new();
+    for (module, value) in modules {
+        flatten(module, value, &mut columns);
+    }
+    
+    let header: Vec<String> = columns.iter().map(|(name, _)| csv_field(name)).collect();
+    let row: Vec<String> = columns.iter().map(|(_, value)| csv_field(value)).collect();
+    format!("{}\r\n{}\r\n", header.join(","), row.join(","))
+}
+
+fn metric_name(parts: &[&str]) -> String {
+    let name: String = parts.join("_")
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_lowercase() } else { '_' })
+        .collect();
+    format!("hyperfetch_{}", name)
+}
+
+fn metric_value(value: &Value) -> Option<String> {
+    match value {
+        Value::Bool(value) => Some(if *value { "1" } else { "0" }.to_string()),
+        Value::Int(value) => Some(value.to_string()),
+        Value::Float(value) => number(*value),
+        _ => None,
+    }
+}
+
+fn label_value(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+const CHANGING_TEXT: &[&str] = &["long", "short", "boot_iso", "summary", "time_remaining"];
+
+fn is_label(name: &str, fields: &Fields) -> bool {
+    let bytes = format!("{}_bytes", name);
+    !CHANGING_TEXT.contains(&name) && !fields.iter().any(|(other, _)| *other == bytes)
+}
+
+fn labels(fields: &Fields, extra: &[(&str, String)]) -> String {
+    let mut pairs: Vec<String> = extra.iter()
+        .map(|(name, value)| format!("{}=\"{}\"", name, label_value(value)))
+        .collect();
+    for (name, value) in fields {
+        if let Value::Text(text) = value {
+            if !text.is_empty() && is_label(name, fields) {
+                pairs.push(format!("{}=\"{}\"", name, label_value(text)));
+            }
+        }
+    }
+    
+    if pairs.is_empty() {
+        String::new()
+    } else {
+        format!("{{{}}}", pairs.join(","))
+    }
+}
+
+#[derive(Default)]
+struct Metrics {
+    families: Vec<(String, Vec<String>)>,
+    index: HashMap<String, usize>,
+}
+
+impl Metrics {
+    fn push(&mut self, name: String, labels: &str, value: String) {
+        let sample = format!("{}{} {}", name, labels, value);
+        let families = &mut self.families;
+        let position = *self.index.entry(name.clone()).or_insert_with(|| {
+            families.push((name, Vec::new()));
+            families.len() - 1
+        });
+        self.families[position].1.push(sample);
+    }
+    
+    fn finish(self) -> String {
+        let mut output = String::new();
+        for (name, samples) in self.families {
+            output.push_str(&format!("# TYPE {} gauge\n", name));
+            for sample in samples {
+                output.push_str(&sample);
+                output.push('\n');
+            }
+        }
+        output
+    }
+}
+
+fn to_prometheus(modules: &Fields) -> String {
+    let mut metrics = Metrics::default();
+    
+    for (module, value) in modules {
+        let Value::Map(fields) = value else {
+            continue;
+        };
+        
+        let info = labels(fields, &[]);
+        if !info.is_empty() {
+            metrics.push(metric_name(&[module, "info"]), &info, "1".to_string());
+        }
+        
+        for (name, value) in fields {
+            if let Some(number) = metric_value(value) {
+                metrics.push(metric_name(&[module, name]), "", number);
+            }
+            
+            let Value::List(items) = value else {
+                continue;
+            };
+            for (index, item) in items.iter().enumerate() {
+                let Value::Map(item_fields) = item else {
+                    continue;
+                };
+                let item_labels = labels(item_fields, &[("index", index.to_string())]);
+                for (item_name, item_value) in item_fields {
+                    if let Some(number) = metric_value(item_value) {
+                        metrics.push(metric_name(&[module, name, item_name]), &item_labels, number);
+                    }
+                }
+            }
+        }
+    }
+    
+    metrics.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn disk(mount: &'static str, percent: f64) -> Value {
+        Value::Map(vec![("mount", mount.into()), ("percent", percent.into())])
+    }
+    
+    #[test]
+    fn prometheus_families_are_contiguous() {
+        let modules: Fields = vec![("disk", Value::Map(vec![
+            ("disks", Value::List(vec![disk("/", 50.0), disk("/home", 25.0)])),
+        ]))];
+        let output = to_prometheus(&modules);
+        let names: Vec<&str> = output.lines()
+            .filter(|line| !line.starts_with('#'))
+            .map(|line| line.split(['{', ' ']).next().unwrap_or(""))
+            .collect();
+        
+        let mut seen: Vec<&str> = Vec::new();
+        for name in &names {
+            if seen.last() != Some(name) {
+                assert!(!seen.contains(name), "family {} is split:\n{}", name, output);
+                seen.push(name);
+            }
+        }
+        assert_eq!(output.matches("# TYPE hyperfetch_disk_disks_percent gauge").count(), 1);
+        assert_eq!(names.iter().filter(|name| **name == "hyperfetch_disk_disks_percent").count(), 2);
+    }
+}
@@ -0,0 +1,269 @@
+/*
+* Copyright (C) 2026 compiledkernel-idk <https://github.com/compiledkernel-idk>
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program. If not, see <https://www.gnu.org/licenses/>
+*/
+
+use std::collections::HashMap;
+use std::iter::Peekable;
+use std::str::Chars;
+use colored::Color;
+use crate::cli::Args;
+use crate::config;
+use crate::modules::{Fields, Value, MODULES};
+use super::colors::{parse_color, Theme};
+
+#[derive(Debug, Clone)]
+enum Node {
+    Text(String),
+    Field { path: String, precision: Option<usize> },
+    Optional(Vec<Node>),
+    Color(Option<Color>),
+}
+
+pub struct Templates {
+    formats: HashMap<String, String>,
+    oneline: HashMap<String, String>,
+}
+
+impl Templates {
+    pub fn load(args: &Args) -> Self {
+        let mut config = config::load();
+        let mut formats = config.remove("format").unwrap_or_default();
+        let oneline = config.remove("oneline").unwrap_or_default();
+        
+        for module in MODULES {
+            if let Some(format) = args.format_override(module) {
+                formats.insert(module.to_string(), format.to_string());
+            }
+        }
+        
+        Templates { formats, oneline }
+    }
+    
+    pub fn get(&self, module: &str) -> Option<&str> {
+        self.formats.get(module).map(String::as_str)
+    }
+    
+    pub fn oneline_setting(&self, key: &str) -> Option<&str> {
+        self.oneline.get(key).map(String::as_str)
+    }
+    
+    pub fn apply(&self, module: &str, fields: Fields, theme: &Theme) -> Option<String> {
+        self.get(module).map(|template| render(template, &fields, theme))
+    }
+}
+
+pub fn render(template: &str, fields: &Fields, theme: &Theme) -> String {
+    let mut chars = template.chars().peekable();
+    let nodes = parse(&mut chars, theme, false);
+    
+    let mut segments = Vec::new();
+    let mut color = None;
+    render_nodes(&nodes, fields, &mut color, &mut segments);
+    
+    segments.into_iter()
+        .filter(|(_, text)| !text.is_empty())
+        .map(|(color, text)| theme.paint(&text, color.unwrap_or(theme.value_color)))
+        .collect()
+}
+
+pub fn lookup<'a>(fields: &'a Fields, path: &str) -> Option<&'a Value> {
+    let mut parts = path.split('.');
+    let first = parts.next()?;
+    let mut value = fields.iter().find(|(name, _)| *name == first).map(|(_, value)| value)?;
+    for part in parts {
+        value = value.get(part)?;
+    }
+    Some(value)
+}
+
+fn parse(chars: &mut Peekable<Chars>, theme: &Theme, nested: bool) -> Vec<Node> {
+    let mut nodes = Vec::new();
+    let mut text = String::new();
+    
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                if let Some(next) = chars.next() {
+                    text.push(next);
+                }
+            }
+            '}' if nested => break,
+            '{' => {
+                if !text.is_empty() {
+                    nodes.push(Node::Text(std::mem::take(&mut text)));
+                }
+                if chars.peek() == Some(&'?') {
+                    chars.next();
+                    nodes.push(Node::Optional(parse(chars, theme, true)));
+                } else {
+                    let mut spec = String::new();
+                    let mut closed = false;
+                    for c in chars.by_ref() {
+                        if c == '}' {
+                            closed = true;
+                            break;
+                        }
+                        spec.push(c);
+                    }
+                    if !closed {
+                        text.push('{');
+                        text.push_str(&spec);
+                        continue;
+                    }
+                    let (path, precision) = match spec.split_once(":.") {
+                        Some((path, precision)) => (path, precision.parse().ok()),
+                        None => (spec.as_str(), None),
+                    };
+                    nodes.push(Node::Field { path: path.trim().to_string(), precision });
+                }
+            }
+            '<' => {
+                let mut lookahead = chars.clone();
+                let tag: String = lookahead.by_ref().take_while(|&c| c != '>').collect();
+                match color_tag(&tag, theme) {
+                    Some(color) => {
+                        if !text.is_empty() {
+                            nodes.push(Node::Text(std::mem::take(&mut text)));
+                        }
+                        nodes.push(Node::Color(color));
+                        *chars = lookahead;
+                    }
+                    None => text.push(c),
+                }
+            }
+            _ => text.push(c),
+        }
+    }
+    
+    if !text.is_empty() {
+        nodes.push(Node::Text(text));
+    }
+    nodes
+}
+
+fn color_tag(tag: &str, theme: &Theme) -> Option<Option<Color>> {
+    if tag == "/" {
+        return Some(None);
+    }
+    
+    let color = match tag {
+        "label" => theme.label_color,
+        "value" => theme.value_color,
+        "title" => theme.title_color,
+        "separator" => theme.separator_color,
+        "good" => theme.bar_good_color,
+        "warn" => theme.bar_warn_color,
+        "bad" => theme.bar_bad_color,
+        _ => parse_color(tag)?,
+    };
+    Some(Some(color))
+}
+
+fn render_nodes(
+    nodes: &[Node],
+    fields: &Fields,
+    color: &mut Option<Color>,
+    segments: &mut Vec<(Option<Color>, String)>,
+) -> bool {
+    let mut complete = true;
+    
+    for node in nodes {
+        match node {
+            Node::Text(text) => segments.push((*color, text.clone())),
+            Node::Color(next) => *color = *next,
+            Node::Field { path, precision } => match lookup(fields, path).filter(|value| !value.is_empty()) {
+                Some(value) => {
+                    let text = match precision {
+                        Some(precision) => value.format_precision(*precision),
+                        None => value.to_string(),
+                    };
+                    segments.push((*color, text));
+                }
+                None => complete = false,
+            },
+            Node::Optional(children) => {
+                let mut inner = Vec::new();
+                let mut inner_color = *color;
+                if render_nodes(children, fields, &mut inner_color, &mut inner) {
+                    segments.extend(inner);
+                    *color = inner_color;
+                }
+            }
+        }
+    }
+    
+    complete
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::colors::get_theme;
+    
+    fn fields() -> Fields {
+        vec![
+            ("name", "Ryzen".into()),
+            ("cores", Value::Int(8)),
+            ("usage", Value::Float(12.345)),
+            ("gpus", Value::List(vec![
+                Value::Map(vec![("model", "RTX 4090".into())]),
+                Value::Map(vec![("model", "Arc A380".into())]),
+            ])),
+        ]
+    }
+    
+    fn plain(template: &str) -> String {
+        colored::control::set_override(false);
+        render(template, &fields(), &get_theme("default").unwrap())
+    }
+    
+    #[test]
+    fn optional_group_drops_out_without_field() {
+        assert_eq!(plain("{name}{? ({missing})}"), "Ryzen");
+        assert_eq!(plain("{name}{? ({cores} cores)}"), "Ryzen (8 cores)");
+        assert_eq!(plain("{name}{? [{cores}{? {missing}}]}"), "Ryzen [8]");
+    }
+    
+    #[test]
+    fn nested_paths() {
+        assert_eq!(plain("{gpus.0.model} + {gpus.1.model}"), "RTX 4090 + Arc A380");
+        assert_eq!(plain("{gpus.2.model}"), "");
+    }
+    
+    #[test]
+    fn precision_on_int_and_float() {
+        assert_eq!(plain("{usage:.1}%"), "12.3%");
+        assert_eq!(plain("{cores:.2}"), "8.00");
+        assert_eq!(plain("{usage}"), "12.35");
+    }
+    
+    #[test]
+    fn escapes_are_literal() {
+        assert_eq!(plain("\\{name\\} \\<red>"), "{name} <red>");
+    }
+    
+    #[test]
+    fn unknown_tag_is_literal() {
+        assert_eq!(plain("<nope>{name}</>"), "<nope>Ryzen");
+        assert_eq!(plain("<red>{name}</> a<b"), "Ryzen a<b");
+    }
+    
+    #[test]
+    fn unterminated_field_is_literal() {
+        assert_eq!(plain("{name} {cores"), "Ryzen {cores");
+        assert_eq!(plain("{?{name}"), "Ryzen");
+    }
+}
@@ -0,0 +1,246 @@
+/*
+* Copyright (C) 2026 compiledkernel-idk <https://github.com/compiledkernel-idk>
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program. If not, see <https://www.gnu.org/licenses/>
+*/
+
+use std::collections::HashMap;
+use std::env;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::os::unix::io::AsRawFd;
+use std::time::{Duration, Instant};
+
+pub type Rgb = (u8, u8, u8);
+
+#[derive(Debug, Clone, Default)]
+pub struct TerminalPalette {
+    pub foreground: Option<Rgb>,
+    pub background: Option<Rgb>,
+    pub colors: HashMap<u8, Rgb>,
+}
+
+const QUERY_TIMEOUT: Duration = Duration::from_millis(150);
+
+pub fn is_interactive() -> bool {
+    unsafe { libc::isatty(libc::STDIN_FILENO) == 1 && libc::isatty(libc::STDOUT_FILENO) == 1 }
+}
+
+pub fn stdout_is_tty() -> bool {
+    unsafe { libc::isatty(libc::STDOUT_FILENO) == 1 }
+}
+
+pub fn query(sequence: &str) -> Option<String> {
+    if !is_interactive() {
+        return None;
+    }
+    
+    let term = env::var("TERM").unwrap_or_default();
+    if term.is_empty() || term == "dumb" || term == "linux" {
+        return None;
+    }
+    
+    let mut tty = OpenOptions::new().read(true).write(true).open("/dev/tty").ok()?;
+    let fd = tty.as_raw_fd();
+    
+    let mut original: libc::termios = unsafe { std::mem::zeroed() };
+    if unsafe { libc::tcgetattr(fd, &mut original) } != 0 {
+        return None;
+    }
+    
+    let mut raw = original;
+    raw.c_lflag &= !(libc::ICANON | libc::ECHO);
+    raw.c_cc[libc::VMIN] = 0;
+    raw.c_cc[libc::VTIME] = 0;
+    if unsafe { libc::tcsetattr(fd, libc::TCSANOW, &raw) } != 0 {
+        return None;
+    }
+    
+    let request = format!("{}\x1b[c", sequence);
+    let response = if tty.write_all(request.as_bytes()).and_then(|_| tty.flush()).is_ok() {
+        read_until_device_attributes(fd)
+    } else {
+        Vec::new()
+    };
+    
+    unsafe { libc::tcsetattr(fd, libc::TCSANOW, &original) };
+    
+    if response.is_empty() {
+        None
+    } else {
+        Some(String::from_utf8_lossy(&response).to_string())
+    }
+}
+
+pub fn query_palette(indices: &[u8]) -> Option<TerminalPalette> {
+    let mut request = String::new();
+    for index in indices {
+        request.push_str(&format!("\x1b]4;{};?\x1b\\", index));
+    }
+    request.push_str("\x1b]10;?\x1b\\\x1b]11;?\x1b\\");
+    
+    let palette = parse_responses(&query(&request)?);
+    if palette.foreground.is_none() && palette.background.is_none() && palette.colors.is_empty() {
+        None
+    } else {
+        Some(palette)
+    }
+}
+
+pub fn supports_sixel() -> bool {
+    query("")
+        .and_then(|response| {
+            let start = response.find("\x1b[?")?;
+            let end = start + response[start..].find('c')?;
+            Some(response[start + 3..end].split(';').any(|attr| attr == "4"))
+        })
+        .unwrap_or(false)
+}
+
+fn window_size() -> Option<libc::winsize> {
+    let mut size: libc::winsize = unsafe { std::mem::zeroed() };
+    [libc::STDOUT_FILENO, libc::STDIN_FILENO, libc::STDERR_FILENO].into_iter()
+        .any(|fd| unsafe { libc::ioctl(fd, libc::TIOCGWINSZ, &mut size) } == 0)
+        .then_some(size)
+        .filter(|size| size.ws_col > 0 && size.ws_row > 0)
+}
+
+pub fn width() -> Option<usize> {
+    window_size()
+        .map(|size| size.ws_col as usize)
+        .or_else(|| env::var("COLUMNS").ok()?.parse().ok())
+}
+
+pub fn cell_size() -> Option<(u32, u32)> {
+    let size = window_size()?;
+    if size.ws_xpixel == 0 || size.ws_ypixel == 0 {
+        return None;
+    }
+    Some((
+        size.ws_xpixel as u32 / size.ws_col as u32,
+        size.ws_ypixel as u32 / size.ws_row as u32,
+    ))
+}
+
+fn read_until_device_attributes(fd: i32) -> Vec<u8> {
+    let deadline = Instant::now() + QUERY_TIMEOUT;
+    let mut response = Vec::new();
+    let mut buffer = [0u8; 256];
+    
+    while let Some(remaining) = deadline.checked_duration_since(Instant::now()) {
+        let mut poll_fd = libc::pollfd { fd, events: libc::POLLIN, revents: 0 };
+        let ready = unsafe { libc::poll(&mut poll_fd, 1, remaining.as_millis() as i32) };
+        if ready <= 0 {
+            break;
+        }
+        
+        let read = unsafe { libc::read(fd, buffer.as_mut_ptr() as *mut libc::c_void, buffer.len()) };
+        if read <= 0 {
+            break;
+        }
+        response.extend_from_slice(&buffer[..read as usize]);
+        
+        if has_device_attributes(&response) {
+            break;
+        }
+    }
+    
+    response
+}
+
+fn has_device_attributes(response: &[u8]) -> bool {
+    response.windows(3)
+        .position(|w| w == b"\x1b[?")
+        .map(|start| response[start..].contains(&b'c'))
+        .unwrap_or(false)
+}
+
+fn parse_responses(response: &str) -> TerminalPalette {
+    let mut palette = TerminalPalette::default();
+    
+    for chunk in response.split("\x1b]").skip(1) {
+        let body = chunk.split(['\x07', '\x1b']).next().unwrap_or("");
+        let mut parts = body.splitn(3, ';');
+        
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some("4"), Some(index), Some(value)) => {
+                if let (Ok(index), Some(rgb)) = (index.parse(), parse_rgb(value)) {
+                    palette.colors.insert(index, rgb);
+                }
+            }
+            (Some("10"), Some(value), None) => palette.foreground = parse_rgb(value),
+            (Some("11"), Some(value), None) => palette.background = parse_rgb(value),
+            _ => {}
+        }
+    }
+    
+    palette
+}
+
+fn parse_rgb(value: &str) -> Option<Rgb> {
+    let channels = value.strip_prefix("rgb:")?;
+    let mut parts = channels.split('/').map(|channel| {
+        let channel = channel.get(..4).unwrap_or(channel);
+        if channel.is_empty() || channel.len() > 4 || !channel.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        let max = (1u32 << (4 * channel.len() as u32)) - 1;
+        u32::from_str_radix(channel, 16).ok().map(|v| (v * 255 / max) as u8)
+    });
+    
+    Some((parts.next()??, parts.next()??, parts.next()??))
+}
+
+pub fn luminance(rgb: Rgb) -> f32 {
+    let linear = |c: u8| {
+        let c = c as f32 / 255.0;
+        if c <= 0.03928 { c / 12.92 } else { ((c + 0.055) / 1.055).powf(2.4) }
+    };
+    0.2126 * linear(rgb.0) + 0.7152 * linear(rgb.1) + 0.0722 * linear(rgb.2)
+}
+
+pub fn contrast_ratio(a: Rgb, b: Rgb) -> f32 {
+    let (la, lb) = (luminance(a), luminance(b));
+    (la.max(lb) + 0.05) / (la.min(lb) + 0.05)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn parses_rgb_channels() {
+        assert_eq!(parse_rgb("rgb:ffff/8080/0000"), Some((255, 128, 0)));
+        assert_eq!(parse_rgb("rgb:ff/80/00"), Some((255, 128, 0)));
+        assert_eq!(parse_rgb("rgb:f/8/0"), Some((255, 136, 0)));
+    }
+    
+    #[test]
+    fn rejects_malformed_rgb() {
+        assert_eq!(parse_rgb("rgb:ffff/8080"), None);
+        assert_eq!(parse_rgb("rgb:zz/00/00"), None);
+        assert_eq!(parse_rgb("rgb:\u{fffd}\u{fffd}/00/00"), None);
+        assert_eq!(parse_rgb("rgb:f\u{fffd}/00/00"), None);
+        assert_eq!(parse_rgb("#ffffff"), None);
+    }
+    
+    #[test]
+    fn parses_palette_responses() {
+        let response = "\x1b]4;1;rgb:cccc/0000/0000\x1b\\\x1b]10;rgb:ffff/ffff/ffff\x07\x1b]11;rgb:0000/0000/0000\x1b\\\x1b[?62;4c";
+        let palette = parse_responses(response);
+        assert_eq!(palette.colors.get(&1), Some(&(204, 0, 0)));
+        assert_eq!(palette.foreground, Some((255, 255, 255)));
+        assert_eq!(palette.background, Some((0, 0, 0)));
+    }
+}